@@ -0,0 +1,122 @@
+//! With the `embed` feature, generates a lookup of the inputs and examples found at build time
+//! per day, which `read_file` prefers over the filesystem. See `src/template/embedded.rs`.
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    if env::var_os("CARGO_FEATURE_EMBED").is_none() {
+        return;
+    }
+
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let config_path = env::var("AOC_CONFIG").unwrap_or_else(|_| "aoc.toml".into());
+    let data_dir = data_dir(&root.join(&config_path));
+
+    println!("cargo:rerun-if-env-changed=AOC_CONFIG");
+    println!("cargo:rerun-if-env-changed=AOC_DATA_DIR");
+    println!("cargo:rerun-if-changed={config_path}");
+
+    // one arm per day, so that a binary only carries the files of its own day.
+    let mut table = String::from(
+        "const fn day_files(year: u16, day: u8) -> &'static [(&'static str, &'static str)] {\n    match (year, day) {\n",
+    );
+    let files = data_files(&data_dir);
+    for (i, (year, day, key, path)) in files.iter().enumerate() {
+        if i == 0 || files[i - 1].0 != *year || files[i - 1].1 != *day {
+            let _ = writeln!(table, "        ({year}, {day}) => &[");
+        }
+        let _ = writeln!(
+            table,
+            "            ({key:?}, include_str!({:?})),",
+            path.display()
+        );
+        if files
+            .get(i + 1)
+            .is_none_or(|next| next.0 != *year || next.1 != *day)
+        {
+            table.push_str("        ],\n");
+        }
+    }
+    table.push_str("        _ => &[],\n    }\n}\n");
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("embedded.rs");
+    fs::write(out, table).unwrap();
+}
+
+/// The `data_dir` from `AOC_DATA_DIR` or the configuration file, relative to the file.
+/// The library cannot be used here, so `--config` and `--set` are not seen, and an invalid
+/// file is left for the library to report.
+fn data_dir(config_path: &Path) -> PathBuf {
+    if let Ok(dir) = env::var("AOC_DATA_DIR") {
+        return dir.into();
+    }
+    let contents = fs::read_to_string(config_path).unwrap_or_default();
+    let dir = toml::de::DeTable::parse(&contents)
+        .ok()
+        .and_then(|table| {
+            let (_, value) = table
+                .get_ref()
+                .iter()
+                .find(|(key, _)| key.get_ref() == "data_dir")?;
+            value.get_ref().as_str().map(ToString::to_string)
+        })
+        .unwrap_or_else(|| "data".into());
+    config_path
+        .parent()
+        .unwrap_or_else(|| Path::new(""))
+        .join(dir)
+}
+
+/// Finds the plaintext inputs and examples of every year with their year and day, keyed by
+/// their folder and name, e.g. `inputs/01.txt`.
+fn data_files(data_dir: &Path) -> Vec<(u16, u8, String, PathBuf)> {
+    let mut files = vec![];
+    let Ok(years) = fs::read_dir(data_dir) else {
+        return files;
+    };
+
+    for entry in years.flatten() {
+        let Ok(year) = entry.file_name().to_string_lossy().parse::<u16>() else {
+            continue;
+        };
+        for folder in ["inputs", "examples"] {
+            let dir = entry.path().join(folder);
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+            // not the whole data directory, it also holds the stores that change on every run.
+            println!("cargo:rerun-if-changed={}", dir.display());
+            for file in entries.flatten() {
+                let name = file.file_name().to_string_lossy().to_string();
+                let Some(day) = file_day(&name) else {
+                    continue;
+                };
+                let path = file.path();
+                let extension = path.extension().and_then(|ext| ext.to_str());
+                if extension == Some("txt") {
+                    files.push((year, day, format!("{folder}/{name}"), path));
+                } else if extension == Some("enc") && !path.with_extension("").exists() {
+                    // the binary would have to decrypt it at run time, with the key and the data folder.
+                    println!(
+                        "cargo:warning=\"{}\" is locked and is not embedded, run `cargo inputs unlock` before building with `embed`.",
+                        path.display()
+                    );
+                }
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
+
+/// The day of a data file, e.g. `1` for `01.txt`, `01-2.txt` or `01.txt.enc`.
+fn file_day(name: &str) -> Option<u8> {
+    let digits = name.split(['.', '-']).next()?;
+    digits.parse().ok().filter(|day| (1..=25).contains(day))
+}
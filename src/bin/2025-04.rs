@@ -124,7 +124,7 @@ mod tests {
     #[test]
     fn test_parse_grid() {
         assert_eq!(
-            Grid::from_str(&advent_of_code::template::read_file("examples", DAY)),
+            Grid::from_str(&advent_of_code::template::read_file("examples", YEAR, DAY)),
             Ok(example_grid()),
         );
     }
@@ -141,13 +141,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(43));
     }
 }
@@ -103,7 +103,7 @@ mod tests {
     #[test]
     fn test_parse_kitchen() {
         assert_eq!(
-            Kitchen::from_str(&advent_of_code::template::read_file("examples", DAY)),
+            Kitchen::from_str(&advent_of_code::template::read_file("examples", YEAR, DAY)),
             Ok(example_kitchen()),
         );
     }
@@ -121,13 +121,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(14));
     }
 }
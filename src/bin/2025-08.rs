@@ -185,7 +185,7 @@ mod tests {
     #[test]
     fn test_parse_decorations() {
         assert_eq!(
-            Decorations::from_str(&advent_of_code::template::read_file("examples", DAY)),
+            Decorations::from_str(&advent_of_code::template::read_file("examples", YEAR, DAY)),
             Ok(example_decorations()),
         );
     }
@@ -222,7 +222,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(40));
     }
 
@@ -236,7 +236,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(25_272));
     }
 }
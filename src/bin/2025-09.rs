@@ -186,14 +186,14 @@ mod tests {
     #[test]
     fn test_read_tiles() {
         assert_eq!(
-            Polygon::from_str(&advent_of_code::template::read_file("examples", DAY)),
+            Polygon::from_str(&advent_of_code::template::read_file("examples", YEAR, DAY)),
             Ok(example_polygon()),
         );
     }
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(50));
     }
 
@@ -262,7 +262,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(24));
     }
 }
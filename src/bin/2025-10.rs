@@ -186,7 +186,7 @@ mod tests {
     #[test]
     fn test_parse_factory() {
         assert_eq!(
-            Factory::from_str(&advent_of_code::template::read_file("examples", DAY)),
+            Factory::from_str(&advent_of_code::template::read_file("examples", YEAR, DAY)),
             Ok(example_factory()),
         );
     }
@@ -201,7 +201,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(7));
     }
 
@@ -224,7 +224,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(33));
     }
 }
@@ -187,20 +187,20 @@ mod tests {
     #[test]
     fn test_parse_network() {
         assert_eq!(
-            Network::from_str(&advent_of_code::template::read_file("examples", DAY)),
+            Network::from_str(&advent_of_code::template::read_file("examples", YEAR, DAY)),
             Ok(example_network()),
         );
     }
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(8));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(2));
     }
 }
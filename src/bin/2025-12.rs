@@ -169,7 +169,7 @@ mod tests {
     #[test]
     fn test_parse_situation() {
         assert_eq!(
-            Situation::from_str(&advent_of_code::template::read_file("examples", DAY)),
+            Situation::from_str(&advent_of_code::template::read_file("examples", YEAR, DAY)),
             Ok(example_situation()),
         );
     }
@@ -188,13 +188,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 }
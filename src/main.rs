@@ -1,81 +1,293 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
-use args::{AppArguments, parse};
-
-#[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::commands::{
+    all, crosscheck, doctor, download, extract_example, harvest, inputs, new_season, read,
+    scaffold, solve, status, submit, time, watch,
+};
+use advent_of_code::template::render::OutputFormat;
 #[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::{clock::SystemClock, commands::today};
+use args::{AppArguments, parse};
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::commands::inputs::InputsAction;
+    use advent_of_code::template::commands::scaffold::ScaffoldOptions;
+    use advent_of_code::template::commands::watch::WatchMode;
+    use advent_of_code::template::config;
+    use advent_of_code::template::render::OutputFormat;
+    use advent_of_code::template::selection::DayFilters;
+    use advent_of_code::template::submission::SubmitOptions;
+    use advent_of_code::template::{Day, DaySet, Year};
     use std::process;
 
     pub enum AppArguments {
         Download {
+            year: Year,
             day: Day,
         },
         Read {
+            year: Year,
+            day: Day,
+            refresh: bool,
+        },
+        Submit {
+            year: Year,
+            day: Day,
+            part: u8,
+            yes: bool,
+            options: SubmitOptions,
+        },
+        Status {
+            year: Year,
+            run_tests: bool,
+        },
+        NewSeason {
+            year: Year,
+            archive_bins: bool,
+            dry_run: bool,
+        },
+        Inputs {
+            year: Year,
+            action: InputsAction,
+            keep: bool,
+        },
+        Doctor {
+            year: Option<Year>,
+            config_error: Option<String>,
+            offline: bool,
+        },
+        Harvest {
+            year: Year,
+            release: bool,
+        },
+        Crosscheck {
+            year: Year,
+            day: Day,
+            cmd: String,
+            release: bool,
+        },
+        ExtractExample {
+            year: Year,
             day: Day,
+            overwrite: bool,
         },
         Scaffold {
+            year: Year,
             day: Day,
             download: bool,
-            overwrite: bool,
+            options: ScaffoldOptions,
         },
         Solve {
+            year: Year,
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            options: SubmitOptions,
+            watch: Option<WatchMode>,
+            format: Option<OutputFormat>,
         },
         All {
+            year: Year,
+            days: Option<DaySet>,
+            filters: DayFilters,
             release: bool,
+            format: Option<OutputFormat>,
         },
         Time {
+            year: Year,
             all: bool,
-            day: Option<Day>,
+            days: Option<DaySet>,
+            filters: DayFilters,
             store: bool,
+            format: Option<OutputFormat>,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
     }
 
+    /// Parses the command and the global options, which apply to every command:
+    /// `--year`, `--config <path>` and `--set key=value` overriding a configuration key.
+    /// Day arguments are validated against the calendar of the resolved year.
+    #[allow(clippy::too_many_lines)]
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-            },
+        let subcommand = args.subcommand()?;
+        let year_flag = args.opt_value_from_str("--year")?;
+        let config_path: Option<String> = args.opt_value_from_str("--config")?;
+        let overrides: Vec<String> = args.values_from_str("--set")?;
+        // the doctor reports an invalid configuration instead of failing on it.
+        let config_error = match config::init(config_path.as_deref(), &overrides) {
+            Ok(_) => None,
+            Err(e) if subcommand.as_deref() == Some("doctor") => Some(e.to_string()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let app_args = match subcommand.as_deref() {
+            Some("all") => {
+                let year = resolve_year(year_flag)?;
+                let release = args.contains("--release");
+                let format = args.opt_value_from_str("--format")?;
+                let filters = parse_filters(&mut args)?;
+
+                AppArguments::All {
+                    year,
+                    days: parse_days(&mut args, year)?,
+                    filters,
+                    release,
+                    format,
+                }
+            }
             Some("time") => {
+                let year = resolve_year(year_flag)?;
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let format = args.opt_value_from_str("--format")?;
+                let filters = parse_filters(&mut args)?;
 
                 AppArguments::Time {
+                    year,
                     all,
-                    day: args.opt_free_from_str()?,
+                    days: parse_days(&mut args, year)?,
+                    filters,
                     store,
+                    format,
                 }
             }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
-            },
-            Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+            Some("download") => {
+                let year = resolve_year(year_flag)?;
+                AppArguments::Download {
+                    year,
+                    day: parse_day(&mut args, year)?,
+                }
+            }
+            Some("read") => {
+                let year = resolve_year(year_flag)?;
+                let refresh = args.contains("--refresh");
+                AppArguments::Read {
+                    year,
+                    day: parse_day(&mut args, year)?,
+                    refresh,
+                }
+            }
+            Some("submit") => {
+                let year = resolve_year(year_flag)?;
+                let yes = args.contains("--yes");
+                let options = SubmitOptions {
+                    force: args.contains("--force"),
+                    wait: args.contains("--wait"),
+                };
+                let day = parse_day(&mut args, year)?;
+                AppArguments::Submit {
+                    year,
+                    day,
+                    part: check_part(args.free_from_str()?, year, day)?,
+                    yes,
+                    options,
+                }
+            }
+            Some("new-season") => {
+                let archive_bins = !args.contains("--keep-bins");
+                let dry_run = args.contains("--dry-run");
+                AppArguments::NewSeason {
+                    year: args.free_from_str()?,
+                    archive_bins,
+                    dry_run,
+                }
+            }
+            Some("status") => AppArguments::Status {
+                year: resolve_year(year_flag)?,
+                run_tests: args.contains("--tests"),
             },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
+            Some("inputs") => {
+                let year = resolve_year(year_flag)?;
+                let keep = args.contains("--keep");
+                AppArguments::Inputs {
+                    year,
+                    action: args.free_from_str()?,
+                    keep,
+                }
+            }
+            Some("doctor") => AppArguments::Doctor {
+                year: year_flag,
+                config_error,
+                offline: args.contains("--offline"),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+            Some("harvest") => AppArguments::Harvest {
+                year: resolve_year(year_flag)?,
                 release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
             },
+            Some("crosscheck") => {
+                let year = resolve_year(year_flag)?;
+                let cmd = args.value_from_str("--cmd")?;
+                let release = args.contains("--release");
+                AppArguments::Crosscheck {
+                    year,
+                    day: parse_day(&mut args, year)?,
+                    cmd,
+                    release,
+                }
+            }
+            Some("extract-example") => {
+                let year = resolve_year(year_flag)?;
+                let overwrite = args.contains("--overwrite");
+                AppArguments::ExtractExample {
+                    year,
+                    day: parse_day(&mut args, year)?,
+                    overwrite,
+                }
+            }
+            Some("scaffold") => {
+                let year = resolve_year(year_flag)?;
+                let download = args.contains("--download");
+                let options = ScaffoldOptions {
+                    overwrite: args.contains("--overwrite"),
+                    overwrite_input: args.contains("--overwrite-input"),
+                    overwrite_example: args.contains("--overwrite-example"),
+                    dry_run: args.contains("--dry-run"),
+                    template: args.opt_value_from_str("--template")?,
+                };
+
+                AppArguments::Scaffold {
+                    year,
+                    day: parse_day(&mut args, year)?,
+                    download,
+                    options,
+                }
+            }
+            Some("solve") => {
+                let year = resolve_year(year_flag)?;
+                let submit = args.opt_value_from_str("--submit")?;
+                let watch = if args.contains("--watch-tests") {
+                    Some(WatchMode::Tests)
+                } else if args.contains("--watch") {
+                    Some(WatchMode::Solve)
+                } else {
+                    None
+                };
+                if watch.is_some() && submit.is_some() {
+                    return Err("`--submit` cannot be combined with `--watch`.".into());
+                }
+                let day = parse_day(&mut args, year)?;
+                AppArguments::Solve {
+                    year,
+                    day,
+                    release: args.contains("--release"),
+                    submit: submit.map(|part| check_part(part, year, day)).transpose()?,
+                    options: SubmitOptions {
+                        force: args.contains("--force"),
+                        wait: args.contains("--wait"),
+                    },
+                    watch,
+                    dhat: args.contains("--dhat"),
+                    format: args.opt_value_from_str("--format")?,
+                }
+            }
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -93,8 +305,53 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Resolves the year from the `--year` option, falling back to the configured `year`.
+    fn resolve_year(year: Option<Year>) -> Result<Year, Box<dyn std::error::Error>> {
+        year.or_else(|| config::get().year).ok_or_else(|| {
+            "no year configured. Pass `--year`, set `year` in aoc.toml or the `AOC_YEAR` environment variable."
+                .into()
+        })
+    }
+
+    fn parse_day(
+        args: &mut pico_args::Arguments,
+        year: Year,
+    ) -> Result<Day, Box<dyn std::error::Error>> {
+        let day: String = args.free_from_str()?;
+        Ok(Day::parse(&day, year)?)
+    }
+
+    /// Checks a part passed on the command line against the parts of `day`.
+    fn check_part(part: u8, year: Year, day: Day) -> Result<u8, String> {
+        if (1..=year.parts(day)).contains(&part) {
+            Ok(part)
+        } else {
+            Err(format!(
+                "Day {day} of {year} has no part {part}, expecting a part between 1 and {}.",
+                year.parts(day)
+            ))
+        }
+    }
+
+    fn parse_days(
+        args: &mut pico_args::Arguments,
+        year: Year,
+    ) -> Result<Option<DaySet>, Box<dyn std::error::Error>> {
+        let days: Option<String> = args.opt_free_from_str()?;
+        Ok(days.map(|days| DaySet::parse(&days, year)).transpose()?)
+    }
+
+    fn parse_filters(args: &mut pico_args::Arguments) -> Result<DayFilters, pico_args::Error> {
+        Ok(DayFilters {
+            unsolved: args.contains("--unsolved"),
+            slowest: args.opt_value_from_str("--slowest")?,
+            changed_since: args.opt_value_from_str("--changed-since")?,
+        })
+    }
 }
 
+#[allow(clippy::too_many_lines)]
 fn main() {
     match parse() {
         Err(err) => {
@@ -102,43 +359,104 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All {
+                year,
+                days,
+                filters,
+                release,
+                format,
+            } => all::handle(
+                year,
+                days,
+                &filters,
+                release,
+                format.unwrap_or_else(OutputFormat::detect),
+            ),
+            AppArguments::Time {
+                year,
+                days,
+                all,
+                filters,
+                store,
+                format,
+            } => time::handle(
+                year,
+                days,
+                all,
+                store,
+                &filters,
+                format.unwrap_or_else(OutputFormat::detect),
+            ),
+            AppArguments::Download { year, day } => download::handle(year, day),
+            AppArguments::Read { year, day, refresh } => read::handle(year, day, refresh),
+            AppArguments::ExtractExample {
+                year,
+                day,
+                overwrite,
+            } => extract_example::handle(year, day, overwrite),
+            AppArguments::Harvest { year, release } => harvest::handle(year, release),
+            AppArguments::Crosscheck {
+                year,
+                day,
+                cmd,
+                release,
+            } => crosscheck::handle(year, day, &cmd, release),
+            AppArguments::Status { year, run_tests } => status::handle(year, run_tests),
+            AppArguments::NewSeason {
+                year,
+                archive_bins,
+                dry_run,
+            } => {
+                if let Err(e) = new_season::handle(year, archive_bins, dry_run) {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                }
+            }
+            AppArguments::Inputs { year, action, keep } => inputs::handle(year, action, keep),
+            AppArguments::Doctor {
+                year,
+                config_error,
+                offline,
+            } => doctor::handle(year, config_error.as_deref(), offline),
+            AppArguments::Submit {
+                year,
+                day,
+                part,
+                yes,
+                options,
+            } => submit::handle(year, day, part, yes, options),
             AppArguments::Scaffold {
+                year,
                 day,
                 download,
-                overwrite,
+                options,
             } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
+                if let Err(e) = scaffold::handle(year, day, &options, download) {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
                 }
             }
             AppArguments::Solve {
+                year,
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                options,
+                watch,
+                format,
+            } => match watch {
+                Some(mode) => watch::handle(
+                    year,
+                    day,
+                    release,
+                    mode,
+                    format.unwrap_or_else(OutputFormat::detect),
+                ),
+                None => solve::handle(year, day, release, dhat, submit, options, format),
+            },
             #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
+            AppArguments::Today { wait } => today::handle(&SystemClock, wait),
         },
     }
 }
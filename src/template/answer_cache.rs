@@ -0,0 +1,225 @@
+//! The latest answer computed for each part, along with the input it was computed from.
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    fs,
+    io::Error,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year, paths};
+
+/// An answer computed by a solution run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CachedAnswer {
+    pub answer: String,
+    /// Hash of the normalised input the answer was computed from, see [`hash_input`].
+    pub input_hash: String,
+    /// Whether the solution returns a numeric type.
+    pub numeric: bool,
+    /// Seconds since the unix epoch.
+    pub computed_at: u64,
+}
+
+impl CachedAnswer {
+    /// Formats the age of the answer relative to `now` (seconds since the unix epoch), e.g. `5m`.
+    #[must_use]
+    pub fn age(&self, now: u64) -> String {
+        let secs = now.saturating_sub(self.computed_at);
+        match secs {
+            0..60 => format!("{secs}s"),
+            60..3600 => format!("{}m", secs / 60),
+            3600..86400 => format!("{}h", secs / 3600),
+            _ => format!("{}d", secs / 86400),
+        }
+    }
+}
+
+/// The latest answers of a year.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AnswerCache {
+    data: HashMap<(Day, u8), CachedAnswer>,
+}
+
+impl AnswerCache {
+    #[must_use]
+    pub fn get(&self, day: Day, part: u8) -> Option<&CachedAnswer> {
+        self.data.get(&(day, part))
+    }
+
+    pub fn set(&mut self, day: Day, part: u8, answer: CachedAnswer) {
+        self.data.insert((day, part), answer);
+    }
+
+    /// Dehydrate the cache to the JSON file of `year`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the file could not be written.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(paths::answer_cache_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the cache from the JSON file of `year`. If not present, returns an empty cache.
+    #[must_use]
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(paths::answer_cache_path(year))
+            .map_err(|x| x.to_string())
+            .and_then(Self::try_from)
+            .unwrap_or_default()
+    }
+}
+
+/// Hashes an input with 64-bit FNV-1a, which is stable across builds and platforms.
+#[must_use]
+pub fn hash_input(input: &[u8]) -> String {
+    let hash = input.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    let mut hex = String::with_capacity(16);
+    let _ = write!(hex, "{hash:016x}");
+    hex
+}
+
+/// Returns the current time in seconds since the unix epoch.
+#[must_use]
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&AnswerCache> for JsonValue {
+    fn from(value: &AnswerCache) -> Self {
+        let data = value
+            .data
+            .iter()
+            .map(|((day, part), cached)| {
+                let map: HashMap<String, Self> = [
+                    ("day".to_string(), Self::String(day.to_string())),
+                    ("part".to_string(), Self::Number(f64::from(*part))),
+                    ("answer".to_string(), Self::String(cached.answer.clone())),
+                    (
+                        "input_hash".to_string(),
+                        Self::String(cached.input_hash.clone()),
+                    ),
+                    ("numeric".to_string(), Self::Boolean(cached.numeric)),
+                    (
+                        "computed_at".to_string(),
+                        Self::String(cached.computed_at.to_string()),
+                    ),
+                ]
+                .into();
+                Self::Object(map)
+            })
+            .collect();
+
+        Self::Object([("data".to_string(), Self::Array(data))].into())
+    }
+}
+
+impl TryFrom<String> for AnswerCache {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let entries = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .and_then(|data| data.get::<Vec<JsonValue>>())
+            .ok_or("expected `json.data` to be an array.")?;
+
+        let mut data = HashMap::new();
+        for entry in entries {
+            let entry = entry
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("expected a cached answer to be an object.")?;
+            let string = |key: &str| {
+                entry
+                    .get(key)
+                    .and_then(|v| v.get::<String>())
+                    .cloned()
+                    .ok_or_else(|| format!("expected `{key}` to be a string."))
+            };
+
+            let day = Day::from_str(&string("day")?).map_err(|e| e.to_string())?;
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let part = entry
+                .get("part")
+                .and_then(|v| v.get::<f64>())
+                .map(|part| *part as u8)
+                .ok_or("expected `part` to be a number.")?;
+            let computed_at = string("computed_at")?
+                .parse()
+                .map_err(|_| "expected `computed_at` to be a timestamp.")?;
+
+            data.insert(
+                (day, part),
+                CachedAnswer {
+                    answer: string("answer")?,
+                    input_hash: string("input_hash")?,
+                    // caches written before the field existed skip the numeric check.
+                    numeric: entry
+                        .get("numeric")
+                        .and_then(|v| v.get::<bool>())
+                        .copied()
+                        .unwrap_or_default(),
+                    computed_at,
+                },
+            );
+        }
+
+        Ok(Self { data })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{AnswerCache, CachedAnswer, hash_input};
+    use crate::day;
+
+    fn cached(computed_at: u64) -> CachedAnswer {
+        CachedAnswer {
+            answer: "1234".into(),
+            input_hash: hash_input(b"1\n2\n"),
+            numeric: true,
+            computed_at,
+        }
+    }
+
+    #[test]
+    fn hashes_inputs_stably() {
+        assert_eq!(hash_input(b""), "cbf29ce484222325");
+        assert_eq!(hash_input(b"a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn formats_ages() {
+        assert_eq!(cached(100).age(130), "30s");
+        assert_eq!(cached(100).age(100 + 5 * 60), "5m");
+        assert_eq!(cached(100).age(100 + 3 * 3600), "3h");
+        assert_eq!(cached(100).age(100 + 2 * 86400), "2d");
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let mut cache = AnswerCache::default();
+        cache.set(day!(3), 2, cached(1_733_000_000));
+
+        let json = JsonValue::from(&cache).stringify().unwrap();
+        assert_eq!(AnswerCache::try_from(json), Ok(cache));
+    }
+}
@@ -0,0 +1,150 @@
+//! Known answers per day and part, e.g. the expected answers of the examples.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::Error,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+
+/// Answers for both parts of a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    data: BTreeMap<Day, [Option<String>; 2]>,
+}
+
+impl Answers {
+    /// Returns the answer of `part` (1 or 2) of `day`, if known.
+    #[must_use]
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        self.data
+            .get(&day)?
+            .get(usize::from(part).checked_sub(1)?)?
+            .as_deref()
+    }
+
+    /// Sets the answer of `part` (1 or 2) of `day`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `part` is not 1 or 2.
+    pub fn set(&mut self, day: Day, part: u8, answer: String) {
+        assert!(part == 1 || part == 2, "part must be 1 or 2");
+        self.data.entry(day).or_default()[usize::from(part) - 1] = Some(answer);
+    }
+
+    /// Iterates over all days with at least one known answer.
+    pub fn iter(&self) -> impl Iterator<Item = (Day, &[Option<String>; 2])> {
+        self.data.iter().map(|(day, answers)| (*day, answers))
+    }
+
+    /// Dehydrate answers to the JSON file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the file could not be written.
+    pub fn store_file(&self, path: &str) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from the JSON file at `path`. If not present, returns no answers.
+    #[must_use]
+    pub fn read_from_file(path: &str) -> Self {
+        fs::read_to_string(path)
+            .map_err(|x| x.to_string())
+            .and_then(Self::try_from)
+            .unwrap_or_default()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let map: HashMap<String, Self> = value
+            .data
+            .iter()
+            .map(|(day, parts)| {
+                let parts: HashMap<String, Self> = [("part_1", &parts[0]), ("part_2", &parts[1])]
+                    .into_iter()
+                    .map(|(key, answer)| {
+                        (
+                            key.to_string(),
+                            answer.clone().map_or(Self::Null, Self::String),
+                        )
+                    })
+                    .collect();
+                (day.to_string(), Self::Object(parts))
+            })
+            .collect();
+
+        Self::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let days = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let mut data = BTreeMap::new();
+        for (day, parts) in days {
+            let day = Day::from_str(day).map_err(|e| e.to_string())?;
+            let parts = parts
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("expected answers of a day to be an object.")?;
+
+            let part = |key: &str| parts.get(key).and_then(|v| v.get::<String>()).cloned();
+            data.insert(day, [part("part_1"), part("part_2")]);
+        }
+
+        Ok(Self { data })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::Answers;
+    use crate::day;
+
+    #[test]
+    fn gets_and_sets_answers() {
+        let mut answers = Answers::default();
+        answers.set(day!(5), 2, "123".into());
+
+        assert_eq!(answers.get(day!(5), 1), None);
+        assert_eq!(answers.get(day!(5), 2), Some("123"));
+        assert_eq!(answers.get(day!(6), 2), None);
+        assert_eq!(answers.get(day!(5), 3), None);
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 1, "143".into());
+        answers.set(day!(12), 1, "#.\n.#".into());
+
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(Answers::try_from(json), Ok(answers));
+    }
+
+    #[test]
+    fn rejects_invalid_days() {
+        assert!(Answers::try_from(r#"{"xx":{"part_1":"1"}}"#.to_string()).is_err());
+    }
+}
@@ -1,10 +1,13 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
+    env,
     fmt::Display,
+    fs,
     process::{Command, Output, Stdio},
+    time::Duration,
 };
 
-use crate::template::Day;
+use crate::template::{Day, Year, config, paths};
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -31,7 +34,7 @@ impl Display for AocCommandError {
 /// be called, could not write output files to the file system, or exits with a
 /// non-zero status.
 pub fn check() -> Result<(), AocCommandError> {
-    Command::new("aoc")
+    Command::new(config::get().backend.command())
         .arg("-V")
         .output()
         .map_err(|_| AocCommandError::CommandNotFound)?;
@@ -43,8 +46,8 @@ pub fn check() -> Result<(), AocCommandError> {
 /// Will return `AocCommandError` if aoc-cli is not present in environment, could not
 /// be called, could not write output files to the file system, or exits with a
 /// non-zero status.
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+pub fn read(year: Year, day: Day) -> Result<Output, AocCommandError> {
+    let puzzle_path = paths::puzzle_path(year, day);
 
     let args = build_args(
         "read",
@@ -53,6 +56,7 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path,
         ],
+        year,
         day,
     );
 
@@ -64,9 +68,9 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
 /// Will return `AocCommandError` if aoc-cli is not present in environment, could not
 /// be called, could not write output files to the file system, or exits with a
 /// non-zero status.
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+pub fn download(year: Year, day: Day) -> Result<Output, AocCommandError> {
+    let input_path = paths::input_path(year, day);
+    let puzzle_path = paths::puzzle_path(year, day);
 
     let args = build_args(
         "download",
@@ -77,6 +81,7 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path.clone(),
         ],
+        year,
         day,
     );
 
@@ -87,47 +92,208 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     Ok(output)
 }
 
+/// Downloads only the puzzle description, leaving the input untouched.
+///
 /// # Errors
 ///
 /// Will return `AocCommandError` if aoc-cli is not present in environment, could not
 /// be called, could not write output files to the file system, or exits with a
 /// non-zero status.
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
-    // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
-    args.push(part.to_string());
-    args.push(result.to_string());
+pub fn download_puzzle(year: Year, day: Day) -> Result<Output, AocCommandError> {
+    let puzzle_path = paths::puzzle_path(year, day);
+
+    let args = build_args(
+        "download",
+        &[
+            "--overwrite".into(),
+            "--puzzle-only".into(),
+            "--puzzle-file".into(),
+            puzzle_path,
+        ],
+        year,
+        day,
+    );
+
     call_aoc_cli(&args)
 }
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+/// The response of the server to a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// An answer was submitted too recently, another one is accepted after the duration.
+    RateLimited(Duration),
+    /// The part was already solved, or is not unlocked yet.
+    WrongLevel,
+    /// A response that is not recognized, holding the raw output of aoc-cli.
+    Unknown(String),
 }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+impl Verdict {
+    /// Classifies the output of `aoc submit`.
+    #[must_use]
+    pub fn parse(output: &str) -> Self {
+        // aoc-cli wraps the response to the terminal width.
+        let text = output.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if text.contains("That's the right answer") {
+            Self::Correct
+        } else if text.contains("your answer is too high") {
+            Self::TooHigh
+        } else if text.contains("your answer is too low") {
+            Self::TooLow
+        } else if text.contains("That's not the right answer") {
+            Self::Incorrect
+        } else if text.contains("You gave an answer too recently") {
+            Self::RateLimited(parse_wait(&text).unwrap_or(Duration::from_secs(60)))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            Self::Unknown(output.trim().to_string())
+        }
+    }
+
+    /// A short name of the verdict, used when storing it.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::TooHigh => "too_high",
+            Self::TooLow => "too_low",
+            Self::Incorrect => "incorrect",
+            Self::RateLimited(_) => "rate_limited",
+            Self::WrongLevel => "wrong_level",
+            Self::Unknown(_) => "unknown",
+        }
+    }
 }
 
-fn get_year() -> Option<u16> {
-    std::env::var("AOC_YEAR").map_or(None, |x| x.parse().ok().or(None))
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "✔ That's the right answer!"),
+            Self::TooHigh => write!(f, "✖ Wrong answer, too high."),
+            Self::TooLow => write!(f, "✖ Wrong answer, too low."),
+            Self::Incorrect => write!(f, "✖ Wrong answer."),
+            Self::RateLimited(wait) => {
+                write!(
+                    f,
+                    "⏳ Answered too recently, {}s left to wait.",
+                    wait.as_secs()
+                )
+            }
+            Self::WrongLevel => write!(f, "✖ This part is already solved or still locked."),
+            Self::Unknown(output) => write!(f, "? Unrecognized response:\n{output}"),
+        }
+    }
 }
 
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
-    let mut cmd_args = args.to_vec();
+/// Parses the time left from e.g. `You have 1m 3s left to wait.`
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (amount, _) = rest.split_once(" left")?;
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+    amount
+        .split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Verifies the session cookie by downloading the input of the first day of `year` to a
+/// temporary file. Nothing is printed.
+///
+/// # Errors
+///
+/// Will return `AocCommandError` if aoc-cli is not present in environment, could not
+/// be called, or exits with a non-zero status, e.g. because the session expired.
+pub fn check_session(year: Year, day: Day) -> Result<(), AocCommandError> {
+    let input_path = env::temp_dir().join("aoc-session-check.txt");
+    let args = build_args(
+        "download",
+        &[
+            "--overwrite".into(),
+            "--input-only".into(),
+            "--input-file".into(),
+            input_path.to_string_lossy().into(),
+        ],
+        year,
+        day,
+    );
+
+    let output = Command::new(config::get().backend.command())
+        .args(args)
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+    let _ = fs::remove_file(input_path);
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
     }
+}
+
+/// Submits `result` and classifies the response.
+///
+/// # Errors
+///
+/// Will return `AocCommandError` if aoc-cli is not present in environment, could not
+/// be called, could not write output files to the file system, or exits with a
+/// non-zero status.
+pub fn submit(year: Year, day: Day, part: u8, result: &str) -> Result<Verdict, AocCommandError> {
+    // workaround: the argument order is inverted for submit.
+    let mut args = build_args("submit", &[], year, day);
+    args.push(part.to_string());
+    args.push(result.to_string());
+    let output = call_aoc_cli_captured(&args)?;
+    Ok(Verdict::parse(&String::from_utf8_lossy(&output.stdout)))
+}
+
+fn build_args(command: &str, args: &[String], year: Year, day: Day) -> Vec<String> {
+    let mut cmd_args = args.to_vec();
 
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    cmd_args.append(&mut vec![
+        "--year".into(),
+        year.to_string(),
+        "--day".into(),
+        day.to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
 
+/// Like [`call_aoc_cli`], but captures stdout so that the response can be inspected.
+fn call_aoc_cli_captured(args: &[String]) -> Result<Output, AocCommandError> {
+    let output = Command::new(config::get().backend.command())
+        .args(args)
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
+}
+
 fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
     // println!("Calling >aoc with: {}", args.join(" "));
-    let output = Command::new("aoc")
+    let output = Command::new(config::get().backend.command())
         .args(args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
@@ -140,3 +306,58 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         Err(AocCommandError::BadExitStatus(output))
     }
 }
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::Verdict;
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            Verdict::parse("That's the right answer!  You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse(
+                "That's not the right answer; your answer is too\nhigh.  Please wait one minute."
+            ),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer.  If you're stuck, ..."),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            Verdict::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Verdict::WrongLevel
+        );
+        assert_eq!(
+            Verdict::parse("Server error"),
+            Verdict::Unknown("Server error".into())
+        );
+    }
+
+    #[test]
+    fn parses_cooldowns() {
+        assert_eq!(
+            Verdict::parse(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait."
+            ),
+            Verdict::RateLimited(Duration::from_secs(34))
+        );
+        assert_eq!(
+            Verdict::parse("You gave an answer too recently. You have 5m\n12s left to wait."),
+            Verdict::RateLimited(Duration::from_secs(312))
+        );
+    }
+}
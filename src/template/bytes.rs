@@ -0,0 +1,183 @@
+//! Helpers for days that take their input as bytes, declared with `solution!(NN, bytes)`.
+//!
+//! Puzzle inputs are ASCII, so parsing `&[u8]` skips UTF-8 decoding and allows indexing by position.
+
+/// Splits `input` into lines without their `\n`, like [`str::lines`].
+pub fn lines(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    input
+        .split_inclusive(|&b| b == b'\n')
+        .map(|line| line.strip_suffix(b"\n").unwrap_or(line))
+}
+
+/// An unsigned integer that can be parsed from ASCII digits.
+pub trait Unsigned: Copy {
+    /// Parses `digits`, returning `None` if it is empty, has a non-digit or overflows.
+    fn parse_ascii(digits: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Unsigned for $t {
+                fn parse_ascii(digits: &[u8]) -> Option<Self> {
+                    if digits.is_empty() {
+                        return None;
+                    }
+                    digits.iter().try_fold(0, |n: Self, &b| {
+                        let digit = b.wrapping_sub(b'0');
+                        if digit > 9 {
+                            return None;
+                        }
+                        n.checked_mul(10)?.checked_add(Self::from(digit))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+
+/// Parses an unsigned integer from ASCII digits, e.g. `parse_uint::<u32>(b"42")`.
+#[must_use]
+pub fn parse_uint<T: Unsigned>(digits: &[u8]) -> Option<T> {
+    T::parse_ascii(digits)
+}
+
+/// Finds all unsigned integers in `input`, which are separated by any non-digit.
+/// Numbers that overflow `T` are skipped.
+pub fn uints<T: Unsigned>(input: &[u8]) -> impl Iterator<Item = T> {
+    input
+        .split(|b| !b.is_ascii_digit())
+        .filter_map(T::parse_ascii)
+}
+
+/// A rectangular grid of cells borrowed from the input, without copying it.
+/// Positions are `(x, y)` with `(0, 0)` in the top left corner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Grid<'a> {
+    /// The input, including the `\n` at the end of each row.
+    cells: &'a [u8],
+    width: usize,
+    height: usize,
+}
+
+impl<'a> Grid<'a> {
+    /// Returns `None` if the rows of `input` have different lengths.
+    #[must_use]
+    pub fn parse(input: &'a [u8]) -> Option<Self> {
+        let width = lines(input).next().map_or(0, <[u8]>::len);
+        let mut height = 0;
+        for line in lines(input) {
+            if line.len() != width {
+                return None;
+            }
+            height += 1;
+        }
+        Some(Self {
+            cells: input,
+            width,
+            height,
+        })
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The distance between the start of two rows, the row plus its `\n`.
+    const fn stride(&self) -> usize {
+        self.width + 1
+    }
+
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> Option<u8> {
+        (x < self.width && y < self.height).then(|| self.cells[y * self.stride() + x])
+    }
+
+    #[must_use]
+    pub fn row(&self, y: usize) -> Option<&'a [u8]> {
+        (y < self.height).then(|| &self.cells[y * self.stride()..][..self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [u8]> {
+        lines(self.cells)
+    }
+
+    /// The position of the first cell holding `byte`, in reading order.
+    #[must_use]
+    pub fn find(&self, byte: u8) -> Option<(usize, usize)> {
+        self.positions(byte).next()
+    }
+
+    /// The positions of all cells holding `byte`, in reading order.
+    pub fn positions(&self, byte: u8) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let stride = self.stride();
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(move |&(_, &b)| b == byte)
+            .map(move |(i, _)| (i % stride, i / stride))
+    }
+
+    /// The positions above, right of, below and left of `(x, y)` that are inside the grid.
+    pub fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .into_iter()
+            .filter_map(move |(dx, dy)| {
+                Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?))
+            })
+            .filter(move |&(x, y)| x < width && y < height)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Grid, lines, parse_uint, uints};
+
+    #[test]
+    fn splits_lines() {
+        let split = |input: &'static [u8]| lines(input).collect::<Vec<_>>();
+        assert_eq!(split(b"ab\ncd\n"), [&b"ab"[..], b"cd"]);
+        assert_eq!(split(b"ab\n\ncd"), [&b"ab"[..], b"", b"cd"]);
+        assert!(split(b"").is_empty());
+    }
+
+    #[test]
+    fn parses_unsigned_integers() {
+        assert_eq!(parse_uint::<u32>(b"1234"), Some(1234));
+        assert_eq!(parse_uint::<u8>(b"255"), Some(255));
+        assert_eq!(parse_uint::<u8>(b"256"), None);
+        assert_eq!(parse_uint::<u64>(b"-1"), None);
+        assert_eq!(parse_uint::<u64>(b""), None);
+        assert_eq!(
+            uints::<u64>(b"3-5 x=10,y=7\n12").collect::<Vec<_>>(),
+            [3, 5, 10, 7, 12]
+        );
+    }
+
+    #[test]
+    fn indexes_grids() {
+        let grid = Grid::parse(b"#.S\n..#\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(2, 0), Some(b'S'));
+        assert_eq!(grid.get(2, 1), Some(b'#'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.row(1), Some(&b"..#"[..]));
+        assert_eq!(grid.find(b'S'), Some((2, 0)));
+        assert_eq!(grid.positions(b'#').collect::<Vec<_>>(), [(0, 0), (2, 1)]);
+        assert_eq!(grid.neighbours(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.rows().count(), 2);
+
+        assert_eq!(Grid::parse(b"#.\n#\n"), None);
+    }
+}
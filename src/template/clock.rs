@@ -0,0 +1,159 @@
+/// Time source for commands that depend on the puzzle unlock schedule.
+use std::{thread, time::Duration};
+
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Utc};
+
+use crate::template::{Day, SERVER_UTC_OFFSET, Year};
+
+/// A source of the current time that can also wait.
+///
+/// Commands take a clock instead of calling [`Utc::now`] directly, so that tests can
+/// move time forward without actually sleeping.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+    fn sleep(&self, duration: Duration);
+}
+
+/// The wall clock of this machine.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+const fn server_offset() -> FixedOffset {
+    FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600).expect("server offset is a valid offset")
+}
+
+/// Returns the puzzle that is unlocked on the server's calendar date at `now`,
+/// or `None` if `now` is outside of the advent calendar.
+#[must_use]
+pub fn puzzle_at(now: DateTime<Utc>) -> Option<(Year, Day)> {
+    puzzle_at_date(now.with_timezone(&server_offset()).date_naive())
+}
+
+/// Returns the time at which the next puzzle after `now` unlocks, along with its year and day.
+/// Puzzles unlock at midnight on the server, from the 1st of december to the last day of the calendar.
+///
+/// # Panics
+///
+/// Will panic if the next unlock is not a representable date, i.e. after the year 9999.
+#[must_use]
+pub fn next_unlock(now: DateTime<Utc>) -> (DateTime<Utc>, Year, Day) {
+    let offset = server_offset();
+    let today = now.with_timezone(&offset).date_naive();
+
+    let tomorrow = today.succ_opt().expect("date is in range");
+    let date = if puzzle_at_date(tomorrow).is_some() {
+        tomorrow
+    } else {
+        // skip to the start of the next calendar.
+        let year = if today.month() < 12 {
+            today.year()
+        } else {
+            today.year() + 1
+        };
+        NaiveDate::from_ymd_opt(year, 12, 1).expect("date is in range")
+    };
+
+    let (year, day) = puzzle_at_date(date).expect("date is part of a calendar");
+    let unlock = offset
+        .from_local_datetime(&date.and_hms_opt(0, 0, 0).expect("midnight is valid"))
+        .single()
+        .expect("fixed offsets are unambiguous");
+
+    (unlock.with_timezone(&Utc), year, day)
+}
+
+fn puzzle_at_date(date: NaiveDate) -> Option<(Year, Day)> {
+    let year = Year::new(u16::try_from(date.year()).ok()?)?;
+    let day = Day::new(u8::try_from(date.day()).ok()?)?;
+    (date.month() == 12 && year.contains(day)).then_some((year, day))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+pub(crate) mod tests {
+    use std::{cell::Cell, time::Duration};
+
+    use chrono::{DateTime, TimeZone, Utc};
+
+    use super::{Clock, next_unlock, puzzle_at};
+    use crate::{day, year};
+
+    /// A clock that only moves forward when slept on.
+    #[cfg(test)]
+    pub struct FakeClock(Cell<DateTime<Utc>>);
+
+    #[cfg(test)]
+    impl FakeClock {
+        pub const fn at(now: DateTime<Utc>) -> Self {
+            Self(Cell::new(now))
+        }
+    }
+
+    #[cfg(test)]
+    impl Clock for FakeClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.0
+                .set(self.0.get() + chrono::Duration::from_std(duration).unwrap());
+        }
+    }
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn finds_puzzle_in_server_time() {
+        // 04:59 UTC is still the previous day on the server.
+        assert_eq!(puzzle_at(utc(2025, 12, 1, 4, 59)), None);
+        assert_eq!(
+            puzzle_at(utc(2025, 12, 1, 5, 0)),
+            Some((year!(2025), day!(1)))
+        );
+        assert_eq!(puzzle_at(utc(2025, 12, 14, 5, 0)), None);
+        assert_eq!(
+            puzzle_at(utc(2024, 12, 25, 5, 0)),
+            Some((year!(2024), day!(25)))
+        );
+    }
+
+    #[test]
+    fn finds_next_unlock() {
+        assert_eq!(
+            next_unlock(utc(2025, 12, 1, 4, 58)),
+            (utc(2025, 12, 1, 5, 0), year!(2025), day!(1))
+        );
+        assert_eq!(
+            next_unlock(utc(2025, 12, 3, 12, 0)),
+            (utc(2025, 12, 4, 5, 0), year!(2025), day!(4))
+        );
+        assert_eq!(
+            next_unlock(utc(2025, 12, 12, 12, 0)),
+            (utc(2026, 12, 1, 5, 0), year!(2026), day!(1))
+        );
+        assert_eq!(
+            next_unlock(utc(2025, 7, 1, 0, 0)),
+            (utc(2025, 12, 1, 5, 0), year!(2025), day!(1))
+        );
+    }
+
+    #[test]
+    fn fake_clock_advances_on_sleep() {
+        let clock = FakeClock::at(utc(2025, 12, 1, 4, 59));
+        clock.sleep(Duration::from_secs(60));
+        assert_eq!(clock.now(), utc(2025, 12, 1, 5, 0));
+    }
+}
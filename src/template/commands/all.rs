@@ -1,5 +1,34 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::render::OutputFormat;
+use crate::template::run_multi::run_multi;
+use crate::template::selection::DayFilters;
+use crate::template::timings::Timings;
+use crate::template::{DaySet, Year};
+
+pub fn handle(
+    year: Year,
+    days: Option<DaySet>,
+    filters: &DayFilters,
+    is_release: bool,
+    format: OutputFormat,
+) {
+    let days_to_run = match filters.apply(
+        year,
+        days.unwrap_or_else(|| DaySet::all(year)),
+        &Timings::read_from_file(year),
+    ) {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("Failed to select days: {e}");
+            process::exit(1);
+        }
+    };
+
+    if days_to_run.is_empty() {
+        println!("No days match the selection.");
+        return;
+    }
+
+    run_multi(year, &days_to_run.into_inner(), is_release, false, format);
 }
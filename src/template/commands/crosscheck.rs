@@ -0,0 +1,284 @@
+use std::{
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+    thread,
+};
+
+use crate::template::{Day, Year, paths, render::Silent, run_multi::child_commands, vault};
+
+/// The placeholder in the reference command that is replaced with the path of the dataset.
+/// Without it, the dataset is written to the command's stdin.
+const INPUT_PLACEHOLDER: &str = "{input}";
+
+/// A data file of a day, e.g. `data/2025/examples/01-2.txt`.
+#[derive(Debug, PartialEq, Eq)]
+struct Dataset {
+    /// The plaintext path, which may only exist as a locked copy.
+    path: String,
+    /// Set for examples that only apply to one part.
+    part: Option<u8>,
+}
+
+/// Runs the solution of `day` and the reference command `cmd` on every input and example
+/// of the day, and reports per part whether their answers agree.
+pub fn handle(year: Year, day: Day, cmd: &str, is_release: bool) {
+    let cmd: Vec<&str> = cmd.split_whitespace().collect();
+    if cmd.is_empty() {
+        eprintln!("The reference command is empty.");
+        process::exit(1);
+    }
+
+    let datasets = datasets(year, day);
+    if datasets.is_empty() {
+        eprintln!("No inputs or examples found for day {day}.");
+        process::exit(1);
+    }
+
+    let mut agreements = 0;
+    let mut disagreements = 0;
+    for dataset in &datasets {
+        println!("{}", dataset.path);
+
+        let ours = match child_commands::run_solution(
+            year,
+            day,
+            false,
+            is_release,
+            Some(&dataset.path),
+            &mut Silent,
+        ) {
+            Ok(results) => results,
+            Err(e) => {
+                eprintln!("  Failed to run solution: {e}");
+                disagreements += 1;
+                continue;
+            }
+        };
+        let reference = match run_reference(&cmd, &dataset.path) {
+            Ok(output) => parse_answers(&output),
+            Err(e) => {
+                eprintln!("  Failed to run reference: {e}");
+                disagreements += 1;
+                continue;
+            }
+        };
+
+        for part in 1..=year.parts(day) {
+            if dataset.part.is_some_and(|only| only != part) {
+                continue;
+            }
+            let ours = ours
+                .iter()
+                .find(|result| result.part == part)
+                .and_then(|result| result.answer.as_deref());
+            let reference = reference[usize::from(part - 1)].as_deref();
+
+            match (ours, reference) {
+                (None, None) => {}
+                (Some(ours), Some(reference)) if ours == reference => {
+                    println!("  Part {part}: ✔ {ours}");
+                    agreements += 1;
+                }
+                (ours, reference) => {
+                    println!(
+                        "  Part {part}: ✖ ours {}, reference {}",
+                        ours.unwrap_or("✖"),
+                        reference.unwrap_or("✖")
+                    );
+                    disagreements += 1;
+                }
+            }
+        }
+    }
+
+    println!(
+        "\n{agreements} part(s) agree, {disagreements} disagree across {} dataset(s).",
+        datasets.len()
+    );
+    if disagreements > 0 {
+        process::exit(1);
+    }
+}
+
+/// The inputs and examples of `day`, including the inputs that are only stored locked.
+fn datasets(year: Year, day: Day) -> Vec<Dataset> {
+    let mut datasets = vec![];
+    for folder in ["inputs", "examples"] {
+        let dir = format!("{}/{folder}", paths::data_dir(year));
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if let Some((name, part)) = dataset_name(&name, day) {
+                datasets.push(Dataset {
+                    path: format!("{dir}/{name}"),
+                    part,
+                });
+            }
+        }
+    }
+
+    // a file and its locked copy are the same dataset.
+    datasets.sort_by(|a, b| a.path.cmp(&b.path));
+    datasets.dedup();
+    datasets
+}
+
+/// Matches the files of `day`, e.g. `01.txt`, `01-2.txt` or `01.txt.enc`, returning the
+/// plaintext name and the part a suffixed example applies to.
+fn dataset_name(name: &str, day: Day) -> Option<(String, Option<u8>)> {
+    let name = name.strip_suffix(".enc").unwrap_or(name);
+    let stem = name.strip_suffix(".txt")?;
+    let day = day.to_string();
+
+    if stem == day {
+        return Some((name.into(), None));
+    }
+    let suffix = stem.strip_prefix(&day)?.strip_prefix('-')?;
+    Some((
+        name.into(),
+        suffix.parse().ok().filter(|part| (1..=2).contains(part)),
+    ))
+}
+
+/// Runs the reference command on the dataset at `path` and returns its stdout.
+fn run_reference(cmd: &[&str], path: &str) -> Result<String, String> {
+    let contents = vault::read(path).map_err(|e| e.to_string())?;
+
+    let as_argument = cmd.iter().any(|arg| arg.contains(INPUT_PLACEHOLDER));
+    // the command cannot decrypt a locked input, so it gets a temporary plaintext copy.
+    let temp_file = (as_argument && !Path::new(path).exists()).then(|| temp_path(path));
+    if let Some(temp_file) = &temp_file {
+        fs::write(temp_file, &contents).map_err(|e| e.to_string())?;
+    }
+    let arg_path = temp_file
+        .as_ref()
+        .map_or_else(|| path.to_string(), |p| p.to_string_lossy().to_string());
+
+    let args: Vec<String> = cmd[1..]
+        .iter()
+        .map(|arg| arg.replace(INPUT_PLACEHOLDER, &arg_path))
+        .collect();
+    let output = Command::new(cmd[0])
+        .args(&args)
+        .stdin(if as_argument {
+            Stdio::null()
+        } else {
+            Stdio::piped()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .and_then(|mut child| {
+            let writer = child.stdin.take().map(|mut stdin| {
+                // written from a thread, so that a command printing as it reads cannot block.
+                thread::spawn(move || {
+                    // the command may exit before reading everything, which is fine.
+                    let _ = stdin.write_all(&contents);
+                })
+            });
+            let output = child.wait_with_output();
+            if let Some(writer) = writer {
+                let _ = writer.join();
+            }
+            output
+        });
+
+    if let Some(temp_file) = temp_file {
+        let _ = fs::remove_file(temp_file);
+    }
+
+    let output = output.map_err(|e| format!("\"{}\": {e}", cmd[0]))?;
+    if !output.status.success() {
+        return Err(format!("\"{}\" exited with {}", cmd[0], output.status));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn temp_path(path: &str) -> PathBuf {
+    let name = Path::new(path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    env::temp_dir().join(format!("aoc-crosscheck-{}-{name}", process::id()))
+}
+
+/// Finds the answers in the output of a reference solution, given on lines like `part1: 42`,
+/// `Part 2 = abc` or `p1 42`. Other lines are ignored, and the last answer of a part wins.
+fn parse_answers(output: &str) -> [Option<String>; 2] {
+    let mut answers = [None, None];
+    for line in output.lines() {
+        let line = line.trim();
+        let lower = line.to_ascii_lowercase();
+        let Some(rest) = ["part", "p"]
+            .iter()
+            .find_map(|prefix| lower.strip_prefix(prefix))
+        else {
+            continue;
+        };
+        let rest = rest.trim_start();
+        let part = match rest.chars().next() {
+            Some('1') => 0,
+            Some('2') => 1,
+            _ => continue,
+        };
+
+        // keep the case of the answer, which the lowercase copy lost.
+        let rest = &line[line.len() - rest.len() + 1..];
+        if !rest.starts_with([':', '=', ' ', '\t']) {
+            continue;
+        }
+        let answer = rest.trim_start_matches([':', '=', ' ', '\t']).trim_end();
+        if !answer.is_empty() {
+            answers[part] = Some(answer.to_string());
+        }
+    }
+    answers
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{dataset_name, parse_answers};
+    use crate::day;
+
+    #[test]
+    fn parses_answers() {
+        assert_eq!(
+            parse_answers("part1: 42\npart2: 7\n"),
+            [Some("42".into()), Some("7".into())]
+        );
+        assert_eq!(
+            parse_answers("Parsing...\nPart 1 = ABC\np2 -3\n"),
+            [Some("ABC".into()), Some("-3".into())]
+        );
+        assert_eq!(parse_answers("part 2:\npart12: 5\n"), [None, None]);
+        assert_eq!(parse_answers("p1: 1\np1: 2\n"), [Some("2".into()), None]);
+    }
+
+    #[test]
+    fn matches_datasets_of_the_day() {
+        assert_eq!(
+            dataset_name("01.txt", day!(1)),
+            Some(("01.txt".into(), None))
+        );
+        assert_eq!(
+            dataset_name("01.txt.enc", day!(1)),
+            Some(("01.txt".into(), None))
+        );
+        assert_eq!(
+            dataset_name("01-2.txt", day!(1)),
+            Some(("01-2.txt".into(), Some(2)))
+        );
+        assert_eq!(
+            dataset_name("01-large.txt", day!(1)),
+            Some(("01-large.txt".into(), None))
+        );
+        assert_eq!(dataset_name("11.txt", day!(1)), None);
+        assert_eq!(dataset_name("01.md", day!(1)), None);
+    }
+}
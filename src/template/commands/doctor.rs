@@ -0,0 +1,383 @@
+use std::{env, fmt::Display, fs, path::Path, process, process::Command};
+
+use crate::day;
+use crate::template::{
+    Day, Year, answers::Answers, aoc_cli, config, paths, readme_benchmarks, timings::Timings, vault,
+};
+
+/// The oldest compiler that builds the template, the `rust-version` declared in `Cargo.toml`.
+const MIN_RUSTC: &str = env!("CARGO_PKG_RUST_VERSION");
+
+/// Where aoc-cli looks for the session cookie, relative to the home and config directories.
+const SESSION_FILE: &str = "adventofcode.session";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Status {
+    Ok,
+    /// A problem that only affects some commands, it does not fail the checkup.
+    Warn,
+    Fail,
+}
+
+/// The outcome of a single check.
+struct Finding {
+    status: Status,
+    subject: String,
+    detail: String,
+    /// What to do about a problem.
+    fix: Option<String>,
+}
+
+impl Finding {
+    fn ok(subject: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            status: Status::Ok,
+            subject: subject.into(),
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn problem(
+        status: Status,
+        subject: impl Into<String>,
+        detail: impl Into<String>,
+        fix: impl Into<String>,
+    ) -> Self {
+        Self {
+            status,
+            subject: subject.into(),
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let icon = match self.status {
+            Status::Ok => "✔",
+            Status::Warn => "!",
+            Status::Fail => "✖",
+        };
+        write!(f, "{icon} {}: {}", self.subject, self.detail)?;
+        if let Some(fix) = &self.fix {
+            write!(f, "\n    → {fix}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks the environment and the data of `year` for common problems and prints how to fix them.
+///
+/// `config_error` is the error that occurred when loading the configuration, if any.
+/// The session is verified against the server unless `offline` is set.
+///
+/// Exits with a non-zero status if a check failed.
+pub fn handle(year: Option<Year>, config_error: Option<&str>, offline: bool) {
+    let mut findings = vec![check_rustc()];
+
+    // everything else depends on the configuration, e.g. the data directory.
+    if let Some(e) = config_error {
+        findings.push(Finding::problem(
+            Status::Fail,
+            "config",
+            e,
+            "fix the value in aoc.toml, the environment or `--set`",
+        ));
+    } else {
+        findings.push(Finding::ok("config", "valid"));
+        findings.extend(check_aoc_cli(year, offline));
+        match year.or_else(|| config::get().year) {
+            Some(year) => {
+                findings.push(check_data_dirs(year));
+                findings.extend(check_inputs(year));
+                findings.extend(check_stores(year));
+                findings.push(check_readme(year));
+                findings.extend(check_staged_plaintext());
+            }
+            None => findings.push(Finding::problem(
+                Status::Fail,
+                "year",
+                "no year configured",
+                "set `year` in aoc.toml, or pass `--year`",
+            )),
+        }
+    }
+
+    for finding in &findings {
+        println!("{finding}");
+    }
+
+    let failures = findings
+        .iter()
+        .filter(|finding| finding.status == Status::Fail)
+        .count();
+    if failures > 0 {
+        eprintln!("\n{failures} problem(s) found.");
+        process::exit(1);
+    }
+    println!("\nNo problems found.");
+}
+
+fn check_rustc() -> Finding {
+    let version = Command::new("rustc")
+        .arg("--version")
+        .output()
+        .ok()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string());
+
+    let Some(version) = version else {
+        return Finding::problem(
+            Status::Fail,
+            "rustc",
+            "not found",
+            "install Rust from https://rustup.rs",
+        );
+    };
+
+    match parse_rustc_version(&version) {
+        Some(found) if parse_version(MIN_RUSTC).is_none_or(|min| found >= min) => {
+            Finding::ok("rustc", version)
+        }
+        Some(_) => Finding::problem(
+            Status::Fail,
+            "rustc",
+            format!("{version} is too old"),
+            format!("run `rustup update`, the template needs {MIN_RUSTC} or later"),
+        ),
+        None => Finding::problem(
+            Status::Warn,
+            "rustc",
+            format!("could not parse `{version}`"),
+            format!("make sure rustc {MIN_RUSTC} or later is installed"),
+        ),
+    }
+}
+
+/// Parses the major and minor version from e.g. `rustc 1.85.0 (4d91de4e4 2025-02-17)`.
+fn parse_rustc_version(version: &str) -> Option<(u32, u32)> {
+    parse_version(version.split_whitespace().nth(1)?)
+}
+
+/// Parses the major and minor version from e.g. `1.88` or `1.90.0-nightly`.
+fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.split(['.', '-']);
+    Some((parts.next()?.parse().ok()?, parts.next()?.parse().ok()?))
+}
+
+fn check_aoc_cli(year: Option<Year>, offline: bool) -> Vec<Finding> {
+    let command = config::get().backend.command();
+    let version = Command::new(command)
+        .arg("-V")
+        .output()
+        .ok()
+        .filter(|output| output.status.success());
+
+    let Some(version) = version else {
+        return vec![Finding::problem(
+            Status::Fail,
+            "aoc-cli",
+            format!("command \"{command}\" not found"),
+            "run `cargo install aoc-cli`",
+        )];
+    };
+    let mut findings = vec![Finding::ok(
+        "aoc-cli",
+        String::from_utf8_lossy(&version.stdout).trim(),
+    )];
+
+    let session_fix = "copy the `session` cookie of adventofcode.com to ~/.adventofcode.session";
+    if !has_session() {
+        findings.push(Finding::problem(
+            Status::Fail,
+            "session",
+            "no session cookie found",
+            session_fix,
+        ));
+        return findings;
+    }
+
+    let year = year.or_else(|| config::get().year);
+    findings.push(match year {
+        _ if offline => Finding::ok("session", "found, not verified (offline)"),
+        None => Finding::ok("session", "found, not verified (no year configured)"),
+        Some(year) => match aoc_cli::check_session(year, day!(1)) {
+            Ok(()) => Finding::ok("session", "valid"),
+            Err(_) => Finding::problem(
+                Status::Fail,
+                "session",
+                format!(
+                    "could not download the input of {year} day 01, the session may have expired"
+                ),
+                session_fix,
+            ),
+        },
+    });
+    findings
+}
+
+/// Returns `true` if aoc-cli can find a session cookie.
+fn has_session() -> bool {
+    if env::var_os("ADVENT_OF_CODE_SESSION").is_some() {
+        return true;
+    }
+    let home = env::var_os("HOME").map(|home| Path::new(&home).to_path_buf());
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(|dir| Path::new(&dir).to_path_buf())
+        .or_else(|| home.as_ref().map(|home| home.join(".config")));
+
+    [
+        home.map(|home| home.join(format!(".{SESSION_FILE}"))),
+        config_dir.map(|dir| dir.join(SESSION_FILE)),
+    ]
+    .into_iter()
+    .flatten()
+    .any(|path| path.is_file())
+}
+
+fn check_data_dirs(year: Year) -> Finding {
+    let missing: Vec<_> = ["inputs", "examples", "puzzles"]
+        .into_iter()
+        .filter(|folder| !Path::new(&format!("{}/{folder}", paths::data_dir(year))).is_dir())
+        .collect();
+
+    if missing.is_empty() {
+        Finding::ok("data", format!("\"{}\" is complete", paths::data_dir(year)))
+    } else {
+        Finding::problem(
+            Status::Warn,
+            "data",
+            format!(
+                "\"{}\" is missing: {}",
+                paths::data_dir(year),
+                missing.join(", ")
+            ),
+            "run `cargo scaffold <day> --download`, which creates them",
+        )
+    }
+}
+
+/// Scaffolded days need an input, otherwise `read_file` panics when the solution runs.
+fn check_inputs(year: Year) -> Vec<Finding> {
+    let scaffolded: Vec<Day> = year
+        .days()
+        .filter(|day| Path::new(&paths::bin_path(year, *day)).exists())
+        .collect();
+
+    let mut findings: Vec<Finding> = scaffolded
+        .iter()
+        .filter_map(|day| {
+            let path = paths::input_path(year, *day);
+            let problem =
+                match fs::metadata(&path).or_else(|_| fs::metadata(vault::locked_path(&path))) {
+                    Err(_) => "is missing",
+                    Ok(metadata) if metadata.len() == 0 => "is empty",
+                    Ok(_) => return None,
+                };
+            Some(Finding::problem(
+                Status::Fail,
+                format!("day {day}"),
+                format!("input \"{path}\" {problem}"),
+                format!("run `cargo download {day}`"),
+            ))
+        })
+        .collect();
+
+    if findings.is_empty() {
+        findings.push(Finding::ok(
+            "inputs",
+            format!("{} scaffolded day(s) have an input", scaffolded.len()),
+        ));
+    }
+    findings
+}
+
+/// Corrupt stores are silently replaced by empty ones when read, losing their contents.
+fn check_stores(year: Year) -> Vec<Finding> {
+    type Parse = fn(String) -> Result<(), String>;
+    let stores: [(&str, String, Parse); 3] = [
+        ("timings", paths::timings_path(year), |json| {
+            Timings::try_from(json).map(|_| ())
+        }),
+        ("answers", paths::answers_path(year), |json| {
+            Answers::try_from(json).map(|_| ())
+        }),
+        (
+            "example answers",
+            paths::example_answers_path(year),
+            |json| Answers::try_from(json).map(|_| ()),
+        ),
+    ];
+
+    stores
+        .into_iter()
+        .filter_map(|(name, path, parse)| {
+            let json = fs::read_to_string(&path).ok()?;
+            Some(match parse(json) {
+                Ok(()) => Finding::ok(name, format!("\"{path}\" is valid")),
+                Err(e) => Finding::problem(
+                    Status::Fail,
+                    name,
+                    format!("\"{path}\" is corrupt: {e}"),
+                    "fix or delete the file, it is reset on the next write",
+                ),
+            })
+        })
+        .collect()
+}
+
+fn check_readme(year: Year) -> Finding {
+    let config = config::get();
+    match readme_benchmarks::check(year) {
+        Ok(()) => Finding::ok(
+            "readme",
+            format!("\"{}\" has a table for {year}", config.readme.path),
+        ),
+        Err(e) => Finding::problem(
+            Status::Warn,
+            "readme",
+            format!("\"{}\": {e}", config.readme.path),
+            format!(
+                "add two lines containing `{}` where `cargo time --store` should write the table",
+                config.readme_marker(year)
+            ),
+        ),
+    }
+}
+
+/// Plaintext inputs must not be published, see `cargo inputs`. Skipped outside of a git repository.
+fn check_staged_plaintext() -> Option<Finding> {
+    let staged = vault::staged_plaintext().ok()?;
+    Some(if staged.is_empty() {
+        Finding::ok("git", "no plaintext inputs or puzzles are staged")
+    } else {
+        Finding::problem(
+            Status::Fail,
+            "git",
+            format!("plaintext staged: {}", staged.join(", ")),
+            "unstage them with `git restore --staged <file>` and commit `cargo inputs lock` instead",
+        )
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{MIN_RUSTC, parse_rustc_version, parse_version};
+
+    #[test]
+    fn parses_rustc_versions() {
+        assert_eq!(
+            parse_rustc_version("rustc 1.85.0 (4d91de4e4 2025-02-17)"),
+            Some((1, 85))
+        );
+        assert_eq!(
+            parse_rustc_version("rustc 1.90.0-nightly (abc 2025-06-01)"),
+            Some((1, 90))
+        );
+        assert_eq!(parse_rustc_version("rustc"), None);
+        assert!(parse_version(MIN_RUSTC).is_some());
+    }
+}
@@ -1,7 +1,7 @@
-use crate::template::{Day, aoc_cli};
-use std::process;
+use crate::template::{Day, Year, aoc_cli, paths};
+use std::{fs, process};
 
-pub fn handle(day: Day) {
+pub fn handle(year: Year, day: Day) {
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -9,8 +9,20 @@ pub fn handle(day: Day) {
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
+    create_data_dirs(year);
+
+    if let Err(e) = aoc_cli::download(year, day) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     }
 }
+
+/// Creates the folders aoc-cli writes to, it does not create them itself.
+pub(crate) fn create_data_dirs(year: Year) {
+    for folder in ["inputs", "puzzles"] {
+        if let Err(e) = fs::create_dir_all(format!("{}/{folder}", paths::data_dir(year))) {
+            eprintln!("Failed to create data folder: {e}");
+            process::exit(1);
+        }
+    }
+}
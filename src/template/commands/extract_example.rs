@@ -0,0 +1,101 @@
+use std::{fmt::Display, fs, io, path::Path, process};
+
+use crate::template::{
+    Day, Year,
+    answers::Answers,
+    paths,
+    puzzle::{self, Example},
+    vault,
+};
+
+#[derive(Debug)]
+pub enum ExtractError {
+    PuzzleNotFound(String),
+    NoExample,
+    Io(String, io::Error),
+}
+
+impl Display for ExtractError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PuzzleNotFound(path) => write!(
+                f,
+                "puzzle \"{path}\" not found. Run `cargo download` first."
+            ),
+            Self::NoExample => write!(f, "the puzzle does not contain an example code block."),
+            Self::Io(path, e) => write!(f, "failed to write \"{path}\": {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ExtractError {}
+
+/// Extracts the examples of a downloaded puzzle into the examples folder.
+///
+/// The example of part one is written to `examples/<day>.txt`. If part two introduces a
+/// different example, it is written to `examples/<day>-2.txt` for [`crate::template::read_file_part`].
+/// Existing examples that are not empty are only replaced if `overwrite` is set.
+/// Expected answers are recorded in the example answers of the year.
+///
+/// # Errors
+///
+/// Will return [`ExtractError`] if the puzzle is not downloaded, has no example, or a file
+/// could not be written.
+pub fn extract(year: Year, day: Day, overwrite: bool) -> Result<(), ExtractError> {
+    let puzzle_path = paths::puzzle_path(year, day);
+    let markdown = vault::read_to_string(&puzzle_path)
+        .map_err(|_| ExtractError::PuzzleNotFound(puzzle_path))?;
+
+    let [one, two] = puzzle::examples(&markdown);
+    let Example {
+        input: Some(input),
+        answer: answer_one,
+    } = one
+    else {
+        return Err(ExtractError::NoExample);
+    };
+
+    write_example(&paths::example_path(year, day), &input, overwrite)?;
+    if let Some(input_two) = two.input.filter(|input_two| *input_two != input) {
+        write_example(
+            &paths::example_part_path(year, day, 2),
+            &input_two,
+            overwrite,
+        )?;
+    }
+
+    let answers_path = paths::example_answers_path(year);
+    let mut answers = Answers::read_from_file(&answers_path);
+    for (part, answer) in [(1, answer_one), (2, two.answer)] {
+        if let Some(answer) = answer {
+            println!("Expected answer of the part {part} example: {answer}");
+            answers.set(day, part, answer);
+        }
+    }
+    answers
+        .store_file(&answers_path)
+        .map_err(|e| ExtractError::Io(answers_path, e))
+}
+
+fn write_example(path: &str, input: &str, overwrite: bool) -> Result<(), ExtractError> {
+    // never replace a hand-pasted example unless asked to.
+    let existing = fs::read_to_string(path).unwrap_or_default();
+    if !existing.trim().is_empty() && !overwrite {
+        println!("Kept existing example \"{path}\", pass `--overwrite` to replace it.");
+        return Ok(());
+    }
+
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent).map_err(|e| ExtractError::Io(path.into(), e))?;
+    }
+    fs::write(path, input).map_err(|e| ExtractError::Io(path.into(), e))?;
+    println!("🎄 Successfully wrote example to \"{path}\".");
+    Ok(())
+}
+
+pub fn handle(year: Year, day: Day, overwrite: bool) {
+    if let Err(e) = extract(year, day, overwrite) {
+        eprintln!("Failed to extract example: {e}");
+        process::exit(1);
+    }
+}
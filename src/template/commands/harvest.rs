@@ -0,0 +1,75 @@
+use std::process;
+
+use crate::template::{
+    Day, Year, answers::Answers, paths, puzzle, render::Silent, run_multi::child_commands, vault,
+};
+
+/// Fills the answers store of `year` with the accepted answers found in the downloaded
+/// puzzle descriptions, then runs the solutions and reports answers that disagree.
+pub fn handle(year: Year, is_release: bool) {
+    let answers_path = paths::answers_path(year);
+    let mut answers = Answers::read_from_file(&answers_path);
+
+    let mut harvested = 0;
+    for day in year.days() {
+        let Ok(markdown) = vault::read_to_string(paths::puzzle_path(year, day)) else {
+            continue;
+        };
+        for (part, answer) in (1..).zip(puzzle::submitted_answers(&markdown)) {
+            answers.set(day, part, answer);
+            harvested += 1;
+        }
+    }
+
+    if let Err(e) = answers.store_file(&answers_path) {
+        eprintln!("Failed to store answers: {e}");
+        process::exit(1);
+    }
+    println!("Harvested {harvested} answer(s) into \"{answers_path}\".");
+
+    let mismatches: usize = answers
+        .iter()
+        .map(|(day, _)| verify(year, day, &answers, is_release))
+        .sum();
+
+    if mismatches > 0 {
+        eprintln!("{mismatches} answer(s) disagree with the current solutions.");
+        process::exit(1);
+    }
+}
+
+/// Runs the solution of `day` and returns the number of parts that disagree with `answers`.
+fn verify(year: Year, day: Day, answers: &Answers, is_release: bool) -> usize {
+    let results =
+        match child_commands::run_solution(year, day, false, is_release, None, &mut Silent) {
+            Ok(results) => results,
+            Err(e) => {
+                eprintln!("Day {day}: {e}");
+                return 1;
+            }
+        };
+
+    let mut mismatches = 0;
+    for part in 1..=year.parts(day) {
+        let Some(expected) = answers.get(day, part) else {
+            continue;
+        };
+        let actual = results
+            .iter()
+            .find(|result| result.part == part)
+            .and_then(|result| result.answer.as_deref());
+
+        match actual {
+            Some(actual) if actual == expected => println!("Day {day} part {part}: ✔ {expected}"),
+            Some(actual) => {
+                mismatches += 1;
+                println!("Day {day} part {part}: ✖ expected {expected}, got {actual}");
+            }
+            None => {
+                mismatches += 1;
+                println!("Day {day} part {part}: ✖ expected {expected}, not solved");
+            }
+        }
+    }
+    mismatches
+}
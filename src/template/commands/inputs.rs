@@ -0,0 +1,168 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+    process,
+    str::FromStr,
+};
+
+use crate::template::{
+    Year, config,
+    vault::{self, Key, VaultError},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputsAction {
+    /// Writes a new random key to the configured key file.
+    Keygen,
+    /// Encrypts the plaintext inputs and puzzles, removing the plaintext unless `--keep` is passed.
+    Lock,
+    /// Restores missing plaintext files from their locked copies.
+    Unlock,
+    /// Fails if plaintext inputs or puzzles are staged, e.g. in a pre-commit hook.
+    Check,
+}
+
+impl FromStr for InputsAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "keygen" => Ok(Self::Keygen),
+            "lock" => Ok(Self::Lock),
+            "unlock" => Ok(Self::Unlock),
+            "check" => Ok(Self::Check),
+            _ => Err(format!(
+                "unknown action `{s}`, expecting one of: keygen, lock, unlock, check."
+            )),
+        }
+    }
+}
+
+pub fn handle(year: Year, action: InputsAction, keep: bool) {
+    match action {
+        InputsAction::Keygen => keygen(),
+        InputsAction::Lock => lock(year, keep),
+        InputsAction::Unlock => unlock(year),
+        InputsAction::Check => check(),
+    }
+}
+
+fn keygen() {
+    let key_file = &config::get().inputs.key_file;
+    let (_, hex) = Key::generate().unwrap_or_else(|e| {
+        eprintln!("Failed to generate a key: {e}");
+        process::exit(1);
+    });
+
+    let mut options = OpenOptions::new();
+    // never replace an existing key, the files locked with it could not be unlocked anymore.
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let result = options
+        .open(key_file)
+        .and_then(|mut file| writeln!(file, "{hex}"));
+    if let Err(e) = result {
+        eprintln!("Failed to write \"{key_file}\": {e}");
+        process::exit(1);
+    }
+
+    println!("🔑 Wrote a new key to \"{key_file}\". Never commit it, and keep a backup:");
+    println!("   the locked inputs cannot be recovered without it.");
+    println!("   Elsewhere, e.g. in CI, pass it as `{}`.", vault::KEY_VAR);
+}
+
+fn load_key() -> Key {
+    Key::load().unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    })
+}
+
+fn lock(year: Year, keep: bool) {
+    let key = load_key();
+    let mut count = 0;
+
+    for day in year.days() {
+        for path in vault::day_files(year, day) {
+            let path = Path::new(&path);
+            if !path.exists() {
+                continue;
+            }
+            let result = vault::lock(&key, path).and_then(|locked| {
+                if !keep {
+                    fs::remove_file(path).map_err(|e| VaultError::Io(path.into(), e))?;
+                }
+                Ok(locked)
+            });
+            match result {
+                Ok(locked) => {
+                    println!("🔒 {} → {}", path.display(), locked.display());
+                    count += 1;
+                }
+                Err(e) => {
+                    eprintln!("Failed to lock: {e}");
+                    process::exit(1);
+                }
+            }
+        }
+    }
+
+    if count == 0 {
+        println!("No plaintext inputs or puzzles to lock.");
+    } else {
+        println!("---");
+        println!("🔒 Locked {count} file(s), the `.enc` files are safe to commit.");
+    }
+}
+
+fn unlock(year: Year) {
+    let key = load_key();
+    let mut count = 0;
+
+    for day in year.days() {
+        for path in vault::day_files(year, day) {
+            let path = Path::new(&path);
+            if path.exists() || !vault::locked_path(path).exists() {
+                continue;
+            }
+            if let Err(e) = vault::unlock(&key, path) {
+                eprintln!("Failed to unlock: {e}");
+                process::exit(1);
+            }
+            println!("🔓 {}", path.display());
+            count += 1;
+        }
+    }
+
+    if count == 0 {
+        println!("No locked inputs or puzzles to unlock.");
+    } else {
+        println!("---");
+        println!("🔓 Unlocked {count} file(s), do not commit them.");
+    }
+}
+
+fn check() {
+    match vault::staged_plaintext() {
+        Ok(staged) if staged.is_empty() => {
+            println!("No plaintext inputs or puzzles are staged.");
+        }
+        Ok(staged) => {
+            eprintln!("Refusing to proceed, plaintext inputs or puzzles are staged:");
+            for path in &staged {
+                eprintln!("  {path}");
+            }
+            eprintln!(
+                "Unstage them with `git restore --staged <file>` and commit the output of `cargo inputs lock` instead."
+            );
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Could not list the staged files: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -1,6 +1,17 @@
 pub mod all;
+pub mod crosscheck;
+pub mod doctor;
 pub mod download;
+pub mod extract_example;
+pub mod harvest;
+pub mod inputs;
+pub mod new_season;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod status;
+pub mod submit;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
+pub mod watch;
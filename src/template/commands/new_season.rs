@@ -0,0 +1,247 @@
+use std::{
+    fmt::{Display, Write},
+    fs, io,
+    path::Path,
+};
+
+use crate::template::{Year, config, paths};
+
+/// Where the bins of past seasons are moved, so that they no longer build, e.g. `archive/2025/bin`.
+const ARCHIVE_DIR: &str = "archive";
+
+/// A single step of starting a new season.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SeasonAction {
+    CreateDir(String),
+    Move { from: String, to: String },
+    Write { path: String, contents: String },
+    Keep { path: String, reason: &'static str },
+}
+
+impl Display for SeasonAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CreateDir(path) => write!(f, "create dir  {path}"),
+            Self::Move { from, to } => write!(f, "move        {from} → {to}"),
+            Self::Write { path, .. } => write!(f, "write       {path}"),
+            Self::Keep { path, reason } => write!(f, "keep        {path} ({reason})"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum NewSeasonError {
+    SameYear(Year),
+    Io(String, io::Error),
+}
+
+impl Display for NewSeasonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SameYear(year) => write!(f, "{year} is already the current season."),
+            Self::Io(path, e) => write!(f, "failed to update \"{path}\": {e}"),
+        }
+    }
+}
+
+impl std::error::Error for NewSeasonError {}
+
+/// Plans the switch from the configured year to `year`:
+///  1. the bins of the previous season are archived, data and timings are already year-scoped.
+///  2. the data folders of `year` are created.
+///  3. the README gets benchmark markers for `year`.
+///  4. `year` is set in the configuration file.
+///
+/// # Errors
+///
+/// Will return [`NewSeasonError`] if `year` is the current season or a file could not be read.
+pub fn plan(year: Year, archive_bins: bool) -> Result<Vec<SeasonAction>, NewSeasonError> {
+    let config = config::get();
+    if config.year == Some(year) {
+        return Err(NewSeasonError::SameYear(year));
+    }
+
+    let mut actions = vec![];
+
+    if let Some(previous) = config.year {
+        if archive_bins {
+            let archive = format!("{ARCHIVE_DIR}/{previous}/bin");
+            let bins: Vec<_> = previous
+                .days()
+                .map(|day| paths::bin_path(previous, day))
+                .filter(|path| Path::new(path).exists())
+                .collect();
+            if !bins.is_empty() && !Path::new(&archive).exists() {
+                actions.push(SeasonAction::CreateDir(archive.clone()));
+            }
+            for from in bins {
+                let name = Path::new(&from)
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                actions.push(SeasonAction::Move {
+                    to: format!("{archive}/{name}"),
+                    from,
+                });
+            }
+        }
+        actions.push(SeasonAction::Keep {
+            path: paths::data_dir(previous),
+            reason: "data and timings are year-scoped",
+        });
+    }
+
+    for folder in ["inputs", "examples", "puzzles"] {
+        let dir = format!("{}/{folder}", paths::data_dir(year));
+        if !Path::new(&dir).exists() {
+            actions.push(SeasonAction::CreateDir(dir));
+        }
+    }
+
+    let readme_path = &config.readme.path;
+    let readme = read_optional(readme_path)?;
+    if let Some(contents) = add_markers(&readme, &config.readme_marker(year)) {
+        actions.push(SeasonAction::Write {
+            path: readme_path.clone(),
+            contents,
+        });
+    }
+
+    let config_path = config::path();
+    actions.push(SeasonAction::Write {
+        path: config_path.into(),
+        contents: set_year(&read_optional(config_path)?, year),
+    });
+
+    Ok(actions)
+}
+
+/// Executes the planned actions.
+///
+/// # Errors
+///
+/// Will return [`NewSeasonError`] if a file could not be moved or written.
+pub fn apply(actions: &[SeasonAction]) -> Result<(), NewSeasonError> {
+    for action in actions {
+        match action {
+            SeasonAction::CreateDir(path) => {
+                fs::create_dir_all(path).map_err(|e| NewSeasonError::Io(path.clone(), e))?;
+            }
+            SeasonAction::Move { from, to } => {
+                fs::rename(from, to).map_err(|e| NewSeasonError::Io(from.clone(), e))?;
+            }
+            SeasonAction::Write { path, contents } => {
+                fs::write(path, contents).map_err(|e| NewSeasonError::Io(path.clone(), e))?;
+            }
+            SeasonAction::Keep { .. } => {}
+        }
+        println!("{action}");
+    }
+    Ok(())
+}
+
+/// Starts the season of `year`, only printing the planned actions if `dry_run` is set.
+///
+/// # Errors
+///
+/// Will return [`NewSeasonError`] if planning or applying fails.
+pub fn handle(year: Year, archive_bins: bool, dry_run: bool) -> Result<(), NewSeasonError> {
+    let actions = plan(year, archive_bins)?;
+
+    if dry_run {
+        println!("Dry run, no files were changed:");
+        for action in &actions {
+            println!("{action}");
+        }
+        return Ok(());
+    }
+
+    apply(&actions)?;
+
+    println!("---");
+    if std::env::var_os(config::env_var("year")).is_some() {
+        println!("Note: `AOC_YEAR` is set in your environment and overrides the configured year.");
+    }
+    println!(
+        "🎄 Happy {year}! Type `cargo today` or `cargo scaffold 1 --download` to get started."
+    );
+    Ok(())
+}
+
+fn read_optional(path: &str) -> Result<String, NewSeasonError> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(contents),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+        Err(e) => Err(NewSeasonError::Io(path.into(), e)),
+    }
+}
+
+/// Appends an empty benchmark table to `readme`, unless it already has one.
+fn add_markers(readme: &str, marker: &str) -> Option<String> {
+    if readme.contains(marker) {
+        return None;
+    }
+    let mut contents = readme.trim_end().to_string();
+    if !contents.is_empty() {
+        contents.push_str("\n\n");
+    }
+    let _ = write!(contents, "{marker}\n{marker}\n");
+    Some(contents)
+}
+
+/// Sets the top-level `year` key of a configuration file, adding it if missing.
+fn set_year(config: &str, year: Year) -> String {
+    let mut lines: Vec<String> = config.lines().map(ToString::to_string).collect();
+    let top_level = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with('['))
+        .unwrap_or(lines.len());
+
+    let existing = lines[..top_level].iter().position(|line| {
+        line.split_once('=')
+            .is_some_and(|(key, _)| key.trim() == "year")
+    });
+    match existing {
+        Some(i) => lines[i] = format!("year = {year}"),
+        None => lines.insert(0, format!("year = {year}")),
+    }
+
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    contents
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{add_markers, set_year};
+    use crate::year;
+
+    #[test]
+    fn replaces_configured_year() {
+        assert_eq!(
+            set_year(
+                "# comment\nyear = 2025 # current\n\n[bench]\nyear = 1\n",
+                year!(2026)
+            ),
+            "# comment\nyear = 2026\n\n[bench]\nyear = 1\n"
+        );
+    }
+
+    #[test]
+    fn adds_missing_year() {
+        assert_eq!(
+            set_year("[bench]\ntarget_ms = 10\n", year!(2026)),
+            "year = 2026\n[bench]\ntarget_ms = 10\n"
+        );
+        assert_eq!(set_year("", year!(2026)), "year = 2026\n");
+    }
+
+    #[test]
+    fn adds_readme_markers_once() {
+        let readme = add_markers("# Title\n", "<!-- 2026 -->").unwrap();
+        assert_eq!(readme, "# Title\n\n<!-- 2026 -->\n<!-- 2026 -->\n");
+        assert_eq!(add_markers(&readme, "<!-- 2026 -->"), None);
+    }
+}
@@ -1,17 +1,95 @@
-use std::process;
+use std::{
+    env,
+    io::{IsTerminal, Write, stdout},
+    process::{self, Command, Stdio},
+};
 
-use crate::template::{Day, aoc_cli};
+use crate::template::{
+    Day, Year, aoc_cli,
+    commands::download,
+    markdown, paths, puzzle,
+    render::OutputFormat,
+    vault::{self, VaultError},
+};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
+/// Shows the puzzle description of `day`, fetching it only if `refresh` is set,
+/// it is not cached yet, or the cached description does not include part two.
+pub fn handle(year: Year, day: Day, refresh: bool) {
+    let cached = vault::read_to_string(paths::puzzle_path(year, day)).ok();
+    let stale = cached.as_deref().is_none_or(|md| !puzzle::has_part_two(md));
+
+    if (refresh || stale)
+        && let Err(e) = fetch(year, day)
+    {
+        // a cached description is still worth showing when offline.
+        if cached.is_none() {
+            eprintln!("failed to fetch puzzle: {e}");
+            process::exit(1);
+        }
+        eprintln!("Failed to refresh puzzle, showing the cached description: {e}");
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    show(year, day);
+}
+
+/// Renders the cached puzzle description of `day` without hitting the network.
+pub fn show(year: Year, day: Day) {
+    let path = paths::puzzle_path(year, day);
+    let markdown = match vault::read_to_string(&path) {
+        Ok(markdown) => markdown,
+        Err(VaultError::Io(..)) => {
+            eprintln!("puzzle \"{path}\" not found. Run `cargo download {day}` first.");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let (width, height) = markdown::terminal_size();
+    let ansi = OutputFormat::detect() == OutputFormat::Ansi;
+    page(
+        &markdown::render(&markdown, width.min(markdown::MAX_WIDTH), ansi),
+        height,
+    );
+}
+
+fn fetch(year: Year, day: Day) -> Result<(), String> {
+    aoc_cli::check().map_err(|_| {
+        "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            .to_string()
+    })?;
+    download::create_data_dirs(year);
+    aoc_cli::download_puzzle(year, day)
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// Prints `text`, piping it through `$PAGER` (or `less`) if it does not fit the terminal.
+fn page(text: &str, height: usize) {
+    if !stdout().is_terminal() || text.lines().count() < height {
+        print!("{text}");
+        return;
+    }
+
+    let pager = env::var("PAGER").unwrap_or_else(|_| "less -R".into());
+    let mut parts = pager.split_whitespace();
+    let child = parts.next().and_then(|cmd| {
+        Command::new(cmd)
+            .args(parts)
+            .stdin(Stdio::piped())
+            .spawn()
+            .ok()
+    });
+
+    let Some(mut child) = child else {
+        print!("{text}");
+        return;
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        // the pager may be quit before reading everything, which is fine.
+        let _ = stdin.write_all(text.as_bytes());
     }
+    let _ = child.wait();
 }
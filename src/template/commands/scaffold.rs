@@ -1,79 +1,516 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
-    process,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
 };
 
-use crate::template::Day;
+use crate::template::{
+    Day, Year,
+    answers::Answers,
+    commands::{download, extract_example},
+    paths, puzzle, vault,
+};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
+/// Directory with user-provided module templates, e.g. `templates/grid.txt`.
+const TEMPLATES_DIR: &str = "templates";
+
+/// Name of the template used if `--template` is not passed.
+const DEFAULT_TEMPLATE: &str = "default";
+
+/// Controls which existing files scaffolding may replace.
+#[derive(Debug, Clone, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ScaffoldOptions {
+    /// Replace an existing solution module.
+    pub overwrite: bool,
+    /// Truncate an existing input file.
+    pub overwrite_input: bool,
+    /// Truncate an existing example file.
+    pub overwrite_example: bool,
+    /// Only print the planned actions without touching the file system.
+    pub dry_run: bool,
+    /// Name of the module template in `templates/`, the default template if not set.
+    pub template: Option<String>,
+}
+
+/// A single step of scaffolding a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileAction {
+    CreateDir(String),
+    Create { path: String, contents: String },
+    Overwrite { path: String, contents: String },
+    Keep(String),
+}
+
+impl Display for FileAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CreateDir(path) => write!(f, "create dir  {path}"),
+            Self::Create { path, .. } => write!(f, "create      {path}"),
+            Self::Overwrite { path, .. } => write!(f, "overwrite   {path}"),
+            Self::Keep(path) => write!(f, "keep        {path}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    ModuleExists(String),
+    UnknownTemplate(String, Vec<String>),
+    Io(String, io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ModuleExists(path) => write!(
+                f,
+                "module file \"{path}\" already exists. Pass `--overwrite` to replace it."
+            ),
+            Self::UnknownTemplate(name, available) => write!(
+                f,
+                "unknown template \"{name}\", expecting one of: {}.",
+                available.join(", ")
+            ),
+            Self::Io(path, e) => write!(f, "failed to write \"{path}\": {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// Plans the file actions needed to scaffold `day`.
+/// Nothing is written, so a conflict is reported before any file is touched.
+///
+/// # Errors
+///
+/// Will return [`ScaffoldError::ModuleExists`] if the module exists and `overwrite` is not set,
+/// or [`ScaffoldError::UnknownTemplate`] if the selected template does not exist.
+pub fn plan(
+    year: Year,
+    day: Day,
+    options: &ScaffoldOptions,
+) -> Result<Vec<FileAction>, ScaffoldError> {
+    let template = load_template(options.template.as_deref())?;
+    let contents = render_template(&template, &TemplateContext::load(year, day));
+    plan_with(year, day, options, contents, |path| {
+        Path::new(path).exists()
+    })
+}
+
+fn plan_with(
+    year: Year,
+    day: Day,
+    options: &ScaffoldOptions,
+    contents: String,
+    exists: impl Fn(&str) -> bool,
+) -> Result<Vec<FileAction>, ScaffoldError> {
+    let mut actions = vec![];
+
+    for folder in ["inputs", "examples", "puzzles"] {
+        let dir = format!("{}/{folder}", paths::data_dir(year));
+        if !exists(&dir) {
+            actions.push(FileAction::CreateDir(dir));
+        }
+    }
+
+    let module_path = paths::bin_path(year, day);
+    actions.push(match (exists(&module_path), options.overwrite) {
+        (false, _) => FileAction::Create {
+            path: module_path,
+            contents,
+        },
+        (true, true) => FileAction::Overwrite {
+            path: module_path,
+            contents,
+        },
+        (true, false) => return Err(ScaffoldError::ModuleExists(module_path)),
+    });
+
+    // data files may hold a downloaded input or a hand-pasted example, keep them by default.
+    // a locked input counts as existing, an empty plaintext file would shadow it.
+    for (path, overwrite) in [
+        (paths::input_path(year, day), options.overwrite_input),
+        (paths::example_path(year, day), options.overwrite_example),
+    ] {
+        let locked = vault::locked_path(&path).to_string_lossy().to_string();
+        actions.push(match (exists(&path) || exists(&locked), overwrite) {
+            (false, _) => FileAction::Create {
+                path,
+                contents: String::new(),
+            },
+            (true, true) => FileAction::Overwrite {
+                path,
+                contents: String::new(),
+            },
+            (true, false) => FileAction::Keep(path),
+        });
+    }
+
+    Ok(actions)
+}
+
+/// Values available to module templates.
+struct TemplateContext {
+    year: Year,
+    day: Day,
+    title: Option<String>,
+    answers: [Option<String>; 2],
+    /// Whether part two has its own example, e.g. `examples/05-2.txt`.
+    part_two_example: bool,
+}
+
+impl TemplateContext {
+    /// Collects the title from the downloaded puzzle and the known examples and answers, if any.
+    fn load(year: Year, day: Day) -> Self {
+        let title = vault::read_to_string(paths::puzzle_path(year, day))
+            .ok()
+            .and_then(|markdown| puzzle::title(&markdown));
+        let answers = Answers::read_from_file(&paths::example_answers_path(year));
+
+        Self {
+            year,
+            day,
+            title,
+            answers: [1, 2].map(|part| answers.get(day, part).map(String::from)),
+            part_two_example: Path::new(&paths::example_part_path(year, day, 2)).exists(),
+        }
+    }
+}
+
+/// Returns the names of all templates, including the built-in default.
+fn available_templates() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(TEMPLATES_DIR)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            (path.extension()? == "txt").then(|| path.file_stem()?.to_str().map(String::from))?
+        })
+        .collect();
+
+    if !names.iter().any(|name| name == DEFAULT_TEMPLATE) {
+        names.push(DEFAULT_TEMPLATE.into());
+    }
+    names.sort_unstable();
+    names
+}
+
+/// Loads a template from `templates/<name>.txt`. The default template falls back to
+/// the built-in one if it is not overridden in `templates/`.
+fn load_template(name: Option<&str>) -> Result<String, ScaffoldError> {
+    let name = name.unwrap_or(DEFAULT_TEMPLATE);
+    let path = format!("{TEMPLATES_DIR}/{name}.txt");
+
+    match fs::read_to_string(&path) {
+        Ok(template) => Ok(template),
+        Err(_) if name == DEFAULT_TEMPLATE => Ok(MODULE_TEMPLATE.into()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ScaffoldError::UnknownTemplate(
+            name.into(),
+            available_templates(),
+        )),
+        Err(e) => Err(ScaffoldError::Io(path, e)),
+    }
+}
+
+/// Substitutes the placeholders of a module template:
+///
+/// - `%DAY_NUMBER%`: the day without padding, e.g. `5`.
+/// - `%DAY%`: the padded day, e.g. `05`.
+/// - `%YEAR%`: the year, e.g. `2025`.
+/// - `%TITLE%`: the puzzle title, or `Day 05` if the puzzle is not downloaded.
+/// - `%PART_ONE_ANSWER%`, `%PART_TWO_ANSWER%`: the example answer as `Some(..)` if it is a known
+///   integer, `None` otherwise. Other answers, e.g. `6,1`, are added as a comment to fill in by hand.
+/// - `%PART_TWO_EXAMPLE%`, `%PART_TWO_EXAMPLE_BYTES%`: reads the example of part two as a string
+///   or as bytes, e.g. `read_file_part("examples", YEAR, DAY, 2)` if it has its own example.
+fn render_template(template: &str, context: &TemplateContext) -> String {
+    let answer = |part: usize| {
+        context.answers[part]
+            .as_deref()
+            .map_or_else(|| "None".into(), answer_literal)
+    };
+
+    let example = |read: &str| {
+        if context.part_two_example {
+            format!("{read}_part(\"examples\", YEAR, DAY, 2)")
+        } else {
+            format!("{read}(\"examples\", YEAR, DAY)")
+        }
+    };
+
+    template
+        .replace("%DAY_NUMBER%", &context.day.into_inner().to_string())
+        .replace("%DAY%", &context.day.to_string())
+        .replace("%YEAR%", &context.year.to_string())
+        .replace(
+            "%TITLE%",
+            &context
+                .title
+                .clone()
+                .unwrap_or_else(|| format!("Day {}", context.day)),
+        )
+        .replace("%PART_ONE_ANSWER%", &answer(0))
+        .replace("%PART_TWO_ANSWER%", &answer(1))
+        .replace("%PART_TWO_EXAMPLE%", &example("read_file"))
+        .replace("%PART_TWO_EXAMPLE_BYTES%", &example("read_bytes"))
+}
+
+/// A known example answer as a literal for `Option<u64>`.
+fn answer_literal(answer: &str) -> String {
+    answer.parse::<u64>().map_or_else(
+        // a block comment, the placeholder is usually followed by code on the same line.
+        |_| {
+            format!(
+                "None /* expected: {} */",
+                format!("{answer:?}").replace("*/", "*\\/")
+            )
+        },
+        |answer| format!("Some({answer})"),
+    )
+}
+
+/// Executes planned file actions in order.
+///
+/// # Errors
+///
+/// Will return [`ScaffoldError::Io`] if a directory or file could not be written. A file
+/// planned as new is never replaced, even if it was created after planning.
+pub fn apply(actions: &[FileAction]) -> Result<(), ScaffoldError> {
+    for action in actions {
+        match action {
+            FileAction::CreateDir(path) => {
+                fs::create_dir_all(path).map_err(|e| ScaffoldError::Io(path.clone(), e))?;
+            }
+            FileAction::Create { path, contents } => {
+                write_file(path, contents, false)?;
+            }
+            FileAction::Overwrite { path, contents } => {
+                write_file(path, contents, true)?;
+            }
+            FileAction::Keep(_) => {}
+        }
+        println!("{action}");
+    }
+    Ok(())
+}
+
+fn write_file(path: &str, contents: &str, overwrite: bool) -> Result<(), ScaffoldError> {
     let mut file = OpenOptions::new();
     if overwrite {
         file.create(true);
     } else {
         file.create_new(true);
     }
-    file.truncate(true).write(true).open(path)
-}
-
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
+    file.truncate(true)
         .write(true)
-        .create(true)
-        .truncate(true)
         .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|e| ScaffoldError::Io(path.to_string(), e))
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Scaffolds the solution module and data files of `day`, downloading the puzzle and
+/// extracting its example first if `download` is set.
+///
+/// # Errors
+///
+/// Will return [`ScaffoldError`] if planning or writing fails. Conflicts are reported
+/// before anything is downloaded.
+pub fn handle(
+    year: Year,
+    day: Day,
+    options: &ScaffoldOptions,
+    download: bool,
+) -> Result<(), ScaffoldError> {
+    let actions = plan(year, day, options)?;
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
+    if options.dry_run {
+        println!("Dry run, no files were written:");
+        for action in &actions {
+            println!("{action}");
         }
+        return Ok(());
+    }
+
+    let actions = if download {
+        download::handle(year, day);
+        let extracted = match extract_example::extract(year, day, options.overwrite_example) {
+            Ok(()) => true,
+            Err(e) => {
+                eprintln!("Skipping example extraction: {e}");
+                false
+            }
+        };
+        // plan again, so that the template gets the puzzle title and example answers,
+        // and the files that were just downloaded are kept.
+        let options = ScaffoldOptions {
+            overwrite_input: false,
+            overwrite_example: options.overwrite_example && !extracted,
+            ..options.clone()
+        };
+        plan(year, day, &options)?
+    } else {
+        actions
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
-        }
+    apply(&actions)?;
+
+    println!("---");
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        FileAction, MODULE_TEMPLATE, ScaffoldError, ScaffoldOptions, TemplateContext, plan_with,
+        render_template,
+    };
+    use crate::{day, year};
+
+    const CONTENTS: &str = "advent_of_code::solution!(5);";
+
+    #[test]
+    fn plans_fresh_day() {
+        let actions = plan_with(
+            year!(2025),
+            day!(5),
+            &ScaffoldOptions::default(),
+            CONTENTS.into(),
+            |_| false,
+        )
+        .unwrap();
+
+        assert_eq!(actions.len(), 6);
+        assert_eq!(actions[0], FileAction::CreateDir("data/2025/inputs".into()));
+        assert!(matches!(
+            &actions[3],
+            FileAction::Create { path, contents }
+                if path == "./src/bin/2025-05.rs" && contents.contains("solution!(5)")
+        ));
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
-        }
+    #[test]
+    fn keeps_existing_data_files() {
+        let options = ScaffoldOptions {
+            overwrite: true,
+            ..ScaffoldOptions::default()
+        };
+        let actions = plan_with(year!(2025), day!(5), &options, CONTENTS.into(), |_| true).unwrap();
+
+        assert_eq!(actions.len(), 3);
+        assert!(matches!(&actions[0], FileAction::Overwrite { .. }));
+        assert_eq!(
+            actions[1],
+            FileAction::Keep("data/2025/inputs/05.txt".into())
+        );
+        assert_eq!(
+            actions[2],
+            FileAction::Keep("data/2025/examples/05.txt".into())
+        );
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
-        }
+    #[test]
+    fn overwrites_data_files_per_flag() {
+        let options = ScaffoldOptions {
+            overwrite: true,
+            overwrite_example: true,
+            ..ScaffoldOptions::default()
+        };
+        let actions = plan_with(year!(2025), day!(5), &options, CONTENTS.into(), |_| true).unwrap();
+
+        assert_eq!(
+            actions[1],
+            FileAction::Keep("data/2025/inputs/05.txt".into())
+        );
+        assert!(matches!(&actions[2], FileAction::Overwrite { .. }));
     }
 
-    println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    #[test]
+    fn refuses_to_replace_module() {
+        let result = plan_with(
+            year!(2025),
+            day!(5),
+            &ScaffoldOptions::default(),
+            CONTENTS.into(),
+            |path| path.ends_with(".rs"),
+        );
+        assert!(matches!(result, Err(ScaffoldError::ModuleExists(_))));
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let context = TemplateContext {
+            year: year!(2025),
+            day: day!(5),
+            title: Some("Cafeteria".into()),
+            answers: [Some("3".into()), None],
+            part_two_example: false,
+        };
+        let rendered = render_template(
+            "// %YEAR% day %DAY%: %TITLE%\nsolution!(%DAY_NUMBER%);\n%PART_ONE_ANSWER% %PART_TWO_ANSWER%",
+            &context,
+        );
+        assert_eq!(
+            rendered,
+            "// 2025 day 05: Cafeteria\nsolution!(5);\nSome(3) None"
+        );
+    }
+
+    #[test]
+    fn comments_non_integer_answers() {
+        let context = TemplateContext {
+            year: year!(2025),
+            day: day!(5),
+            title: None,
+            answers: [Some("6,1".into()), Some("#.\n*/".into())],
+            part_two_example: false,
+        };
+        let rendered = render_template("%PART_ONE_ANSWER%);\n%PART_TWO_ANSWER%);", &context);
+        assert_eq!(
+            rendered,
+            "None /* expected: \"6,1\" */);\nNone /* expected: \"#.\\n*\\/\" */);"
+        );
+    }
+
+    #[test]
+    fn renders_builtin_template_without_puzzle() {
+        let context = TemplateContext {
+            year: year!(2025),
+            day: day!(5),
+            title: None,
+            answers: [None, None],
+            part_two_example: false,
+        };
+        let rendered = render_template(MODULE_TEMPLATE, &context);
+        assert!(rendered.contains("advent_of_code::solution!(5);"));
+        assert!(!rendered.contains('%'));
+    }
+
+    #[test]
+    fn reads_own_example_of_part_two() {
+        let mut context = TemplateContext {
+            year: year!(2025),
+            day: day!(5),
+            title: None,
+            answers: [None, None],
+            part_two_example: false,
+        };
+        let template = "part_two(&%PART_TWO_EXAMPLE%) part_two(&%PART_TWO_EXAMPLE_BYTES%)";
+        assert_eq!(
+            render_template(template, &context),
+            "part_two(&read_file(\"examples\", YEAR, DAY)) part_two(&read_bytes(\"examples\", YEAR, DAY))"
+        );
+
+        context.part_two_example = true;
+        assert_eq!(
+            render_template(template, &context),
+            "part_two(&read_file_part(\"examples\", YEAR, DAY, 2)) part_two(&read_bytes_part(\"examples\", YEAR, DAY, 2))"
+        );
+    }
 }
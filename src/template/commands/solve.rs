@@ -1,13 +1,27 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::render::OutputFormat;
+use crate::template::submission::SubmitOptions;
+use crate::template::{Day, Year, config, paths};
 
 /// # Panics
 ///
 /// Will panic if the Cargo command spawned by this function fails to start
 /// or panics itself during execution.
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(
+    year: Year,
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    options: SubmitOptions,
+    format: Option<OutputFormat>,
+) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        paths::bin_name(year, day),
+    ];
 
     if dhat {
         cmd_args.extend([
@@ -25,14 +39,25 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
+        if options.force {
+            cmd_args.push("--force".to_string());
+        }
+        if options.wait {
+            cmd_args.push("--wait".to_string());
+        }
     }
 
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
+    if let Some(format) = format {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(format.to_string());
+    }
+
+    let mut cmd = Command::new("cargo");
+    cmd.args(&cmd_args)
         .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+        .stderr(Stdio::inherit());
+    config::get().export(&mut cmd);
+    let mut cmd = cmd.spawn().unwrap();
 
     cmd.wait().unwrap();
 }
@@ -0,0 +1,231 @@
+use std::{
+    fmt::Write,
+    fs,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, Day, Year, all_days, answer_cache::AnswerCache, answers::Answers, paths,
+    puzzle, render::OutputFormat, run_multi::get_path_for_bin, timings::Timings, vault,
+};
+
+const ANSI_GREEN: &str = "\x1b[32m";
+const ANSI_RED: &str = "\x1b[31m";
+
+const HEADER: [&str; 10] = [
+    "Day", "Bin", "Input", "Example", "Tests", "Ans 1", "Ans 2", "★ 1", "★ 2", "Timing",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Cell {
+    Yes,
+    No,
+    /// The check was skipped, e.g. because the bin is not scaffolded yet.
+    Unknown,
+    /// The final day has no second part.
+    NotApplicable,
+}
+
+impl Cell {
+    const fn from(value: bool) -> Self {
+        if value { Self::Yes } else { Self::No }
+    }
+}
+
+/// The progress on a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct DayStatus {
+    day: Day,
+    scaffolded: Cell,
+    input: Cell,
+    example: Cell,
+    tests: Cell,
+    /// An answer was computed by the latest run of a part.
+    answers: [Cell; 2],
+    /// The answer of a part was accepted.
+    stars: [Cell; 2],
+    timed: Cell,
+}
+
+/// Prints a matrix of what is done and what is left for every day of `year`.
+/// The example tests of scaffolded days are only run with `run_tests` (`--tests`), as they
+/// take a `cargo test` run per day.
+pub fn handle(year: Year, run_tests: bool) {
+    let stores = Stores {
+        cache: AnswerCache::read_from_file(year),
+        accepted: Answers::read_from_file(&paths::answers_path(year)),
+        timings: Timings::read_from_file(year),
+    };
+    let rows: Vec<DayStatus> = all_days(year)
+        .map(|day| day_status(year, day, &stores, run_tests))
+        .collect();
+
+    let ansi = OutputFormat::detect() == OutputFormat::Ansi;
+    print!("{}", draw(&rows, ansi));
+}
+
+/// The stores of a year, which are read once for all days.
+struct Stores {
+    cache: AnswerCache,
+    accepted: Answers,
+    timings: Timings,
+}
+
+fn day_status(year: Year, day: Day, stores: &Stores, run_tests: bool) -> DayStatus {
+    let scaffolded = Path::new(&get_path_for_bin(year, day)).exists();
+    let non_empty = |path: String| {
+        fs::metadata(&path)
+            .or_else(|_| fs::metadata(vault::locked_path(&path)))
+            .is_ok_and(|m| m.len() > 0)
+    };
+
+    let tests = if !scaffolded || !run_tests {
+        Cell::Unknown
+    } else {
+        Cell::from(
+            Command::new("cargo")
+                .args(["test", "--quiet", "--bin", &paths::bin_name(year, day)])
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()
+                .is_ok_and(|status| status.success()),
+        )
+    };
+
+    let submitted = vault::read_to_string(paths::puzzle_path(year, day))
+        .map(|markdown| puzzle::submitted_answers(&markdown))
+        .unwrap_or_default();
+
+    let part = |part: u8, known: bool| {
+        if part > year.parts(day) {
+            Cell::NotApplicable
+        } else {
+            Cell::from(known)
+        }
+    };
+
+    DayStatus {
+        day,
+        scaffolded: Cell::from(scaffolded),
+        input: Cell::from(non_empty(paths::input_path(year, day))),
+        example: Cell::from(non_empty(paths::example_path(year, day))),
+        tests,
+        answers: [1, 2].map(|p| part(p, stores.cache.get(day, p).is_some())),
+        stars: [1, 2].map(|p| {
+            let known = stores.accepted.get(day, p).is_some() || submitted.len() >= usize::from(p);
+            part(p, known)
+        }),
+        timed: Cell::from(stores.timings.is_day_complete(year, day)),
+    }
+}
+
+fn draw(rows: &[DayStatus], ansi: bool) -> String {
+    let style = |code: &'static str| if ansi { code } else { "" };
+    let widths = HEADER.map(|title| title.chars().count());
+
+    let mut out = String::new();
+    let header: Vec<String> = HEADER
+        .iter()
+        .zip(widths)
+        .map(|(title, width)| format!("{title:<width$}"))
+        .collect();
+    let _ = writeln!(
+        out,
+        "{}{}{}",
+        style(ANSI_BOLD),
+        header.join("  "),
+        style(ANSI_RESET)
+    );
+
+    for row in rows {
+        let cells = [
+            row.scaffolded,
+            row.input,
+            row.example,
+            row.tests,
+            row.answers[0],
+            row.answers[1],
+            row.stars[0],
+            row.stars[1],
+            row.timed,
+        ];
+        let mut line = format!("{:<width$}", row.day.to_string(), width = widths[0]);
+        for (cell, width) in cells.iter().zip(&widths[1..]) {
+            let (symbol, color) = match cell {
+                Cell::Yes => ("✔", style(ANSI_GREEN)),
+                Cell::No => ("✖", style(ANSI_RED)),
+                Cell::Unknown => ("·", ""),
+                Cell::NotApplicable => ("–", ""),
+            };
+            let _ = write!(
+                line,
+                "  {color}{symbol:<width$}{}",
+                if color.is_empty() {
+                    ""
+                } else {
+                    style(ANSI_RESET)
+                }
+            );
+        }
+        let _ = writeln!(out, "{}", line.trim_end());
+    }
+
+    let stars = rows
+        .iter()
+        .flat_map(|row| row.stars)
+        .filter(|cell| *cell == Cell::Yes)
+        .count();
+    let total = rows
+        .iter()
+        .flat_map(|row| row.stars)
+        .filter(|cell| *cell != Cell::NotApplicable)
+        .count();
+    let _ = writeln!(out, "\n{stars}/{total} stars");
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Cell, DayStatus, draw};
+    use crate::day;
+
+    #[test]
+    fn draws_matrix() {
+        let rows = [
+            DayStatus {
+                day: day!(1),
+                scaffolded: Cell::Yes,
+                input: Cell::Yes,
+                example: Cell::Yes,
+                tests: Cell::Yes,
+                answers: [Cell::Yes, Cell::Yes],
+                stars: [Cell::Yes, Cell::No],
+                timed: Cell::No,
+            },
+            DayStatus {
+                day: day!(12),
+                scaffolded: Cell::No,
+                input: Cell::No,
+                example: Cell::No,
+                tests: Cell::Unknown,
+                answers: [Cell::No, Cell::NotApplicable],
+                stars: [Cell::No, Cell::NotApplicable],
+                timed: Cell::No,
+            },
+        ];
+
+        assert_eq!(
+            draw(&rows, false),
+            "\
+Day  Bin  Input  Example  Tests  Ans 1  Ans 2  ★ 1  ★ 2  Timing
+01   ✔    ✔      ✔        ✔      ✔      ✔      ✔    ✖    ✖
+12   ✖    ✖      ✖        ·      ✖      –      ✖    –    ✖
+
+1/3 stars
+"
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use std::{
+    io::{self, Write},
+    process,
+};
+
+use crate::template::{
+    Day, Year,
+    answer_cache::{self, AnswerCache},
+    aoc_cli::Verdict,
+    config,
+    submission::{self, SubmitOptions},
+    try_read_bytes,
+};
+
+/// Submits the cached answer of `part`, which was computed by the latest `cargo solve` run.
+/// The part is checked against the parts of `day` when the arguments are parsed.
+///
+/// Asks for confirmation unless `yes` is set or `submit.confirm` is disabled.
+/// Exits with a non-zero status unless the answer is accepted.
+pub fn handle(year: Year, day: Day, part: u8, yes: bool, options: SubmitOptions) {
+    let cache = AnswerCache::read_from_file(year);
+    let Some(cached) = cache.get(day, part) else {
+        eprintln!("No answer cached for day {day} part {part}. Run `cargo solve {day}` first.");
+        process::exit(1);
+    };
+
+    // hashed as the solution saw it, see `runner::cache_answer`.
+    let input_hash = try_read_bytes("inputs", year, day)
+        .map(|input| answer_cache::hash_input(&input))
+        .ok();
+    if input_hash.as_deref() != Some(cached.input_hash.as_str()) {
+        eprintln!(
+            "Warning: the input changed since the answer was computed. Run `cargo solve {day}` again."
+        );
+    }
+
+    println!("Day {day} part {part}: {}", cached.answer);
+    println!(
+        "Computed {} ago from input {}.",
+        cached.age(answer_cache::now()),
+        &cached.input_hash[..8.min(cached.input_hash.len())]
+    );
+
+    if !yes && config::get().submit.confirm && !confirm("Submit this answer?") {
+        println!("Not submitted.");
+        return;
+    }
+
+    let verdict = submission::submit(year, day, part, &cached.answer, cached.numeric, options);
+    if verdict != Some(Verdict::Correct) {
+        process::exit(1);
+    }
+}
+
+/// Asks a yes/no question on the terminal, defaulting to no.
+fn confirm(question: &str) -> bool {
+    print!("{question} [y/N] ");
+    let _ = io::stdout().flush();
+
+    let mut line = String::new();
+    io::stdin().read_line(&mut line).is_ok() && matches!(line.trim(), "y" | "Y" | "yes")
+}
@@ -1,42 +1,63 @@
-use std::collections::HashSet;
+use std::process;
 
+use crate::template::render::OutputFormat;
 use crate::template::run_multi::run_multi;
+use crate::template::selection::DayFilters;
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{DaySet, Year, readme_benchmarks};
 
 /// # Panics
 ///
 /// Will panic if there is an error when trying to write a timings file to disc.
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
-
-    let days_to_run = day.map_or_else(
-        || {
-            if run_all {
-                all_days().collect()
-            } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
-                    .collect()
-            }
-        },
-        |day| HashSet::from([day]),
-    );
+pub fn handle(
+    year: Year,
+    days: Option<DaySet>,
+    run_all: bool,
+    store: bool,
+    filters: &DayFilters,
+    format: OutputFormat,
+) {
+    let stored_timings = Timings::read_from_file(year);
+
+    let days_to_run = days.unwrap_or_else(|| {
+        if run_all {
+            DaySet::all(year)
+        } else {
+            // when the `--all` flag is not set, filter out days that are fully benched.
+            year.days()
+                .filter(|day| !stored_timings.is_day_complete(year, *day))
+                .collect()
+        }
+    });
+
+    let days_to_run = match filters.apply(year, days_to_run, &stored_timings) {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("Failed to select days: {e}");
+            process::exit(1);
+        }
+    };
+
+    if days_to_run.is_empty() {
+        println!("No days match the selection.");
+        return;
+    }
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(year, &days_to_run.into_inner(), true, true, format).unwrap();
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+            Err(e) => {
+                eprintln!(
+                    "Failed to store updated benchmarks: {e}. Run `cargo doctor` for details."
+                );
             }
         }
     }
@@ -0,0 +1,182 @@
+use std::{
+    io::{self, Write},
+    process,
+    time::Duration,
+};
+
+use crate::template::{
+    Day, Year, aoc_cli,
+    clock::{self, Clock},
+    commands::{
+        download, extract_example, read,
+        scaffold::{self, ScaffoldOptions},
+    },
+};
+
+/// How long to wait before the first retry of a failed download.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// The longest time to wait between two download attempts.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// How many times a download is attempted before giving up.
+const MAX_ATTEMPTS: u32 = 10;
+
+pub fn handle(clock: &impl Clock, wait: bool) {
+    let (year, day) = match clock::puzzle_at(clock.now()) {
+        Some(puzzle) => puzzle,
+        None if wait => wait_for_unlock(clock),
+        None => {
+            eprintln!(
+                "`today` command can only be run on a day of the advent calendar. \
+                Use `--wait` to wait for the next puzzle or `scaffold` with a specific day."
+            );
+            process::exit(1);
+        }
+    };
+
+    if wait {
+        // the input might not be available right at unlock, retry until it is.
+        if aoc_cli::check().is_err() {
+            eprintln!(
+                "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            );
+            process::exit(1);
+        }
+        download::create_data_dirs(year);
+        if let Err(e) = with_backoff(clock, || aoc_cli::download(year, day)) {
+            eprintln!("failed to call aoc-cli: {e}");
+            process::exit(1);
+        }
+    } else {
+        download::handle(year, day);
+    }
+
+    if let Err(e) = extract_example::extract(year, day, false) {
+        eprintln!("Skipping example extraction: {e}");
+    }
+
+    // an existing module is not fatal here, the puzzle is downloaded already.
+    if let Err(e) = scaffold::handle(year, day, &ScaffoldOptions::default(), false) {
+        eprintln!("Skipping scaffold: {e}");
+    }
+
+    read::show(year, day);
+}
+
+/// Shows a countdown until the next puzzle unlocks and returns it.
+fn wait_for_unlock(clock: &impl Clock) -> (Year, Day) {
+    let (unlock, year, day) = clock::next_unlock(clock.now());
+
+    while let Ok(remaining) = (unlock - clock.now()).to_std() {
+        if remaining.is_zero() {
+            break;
+        }
+        print!(
+            "\r⏳ Day {day} of {year} unlocks in {}",
+            format_countdown(remaining)
+        );
+        let _ = io::stdout().flush();
+        clock.sleep(remaining.min(Duration::from_secs(1)));
+    }
+
+    println!("\r🎄 Day {day} of {year} is unlocked!          ");
+    (year, day)
+}
+
+/// Calls `attempt` until it succeeds, doubling the wait between attempts.
+/// Returns the last error after [`MAX_ATTEMPTS`] failed attempts.
+fn with_backoff<T, E: std::fmt::Display>(
+    clock: &impl Clock,
+    mut attempt: impl FnMut() -> Result<T, E>,
+) -> Result<T, E> {
+    let mut backoff = INITIAL_BACKOFF;
+    let mut attempts = 1;
+
+    loop {
+        match attempt() {
+            Ok(value) => return Ok(value),
+            Err(e) if attempts >= MAX_ATTEMPTS => return Err(e),
+            Err(e) => {
+                eprintln!(
+                    "Download failed ({e}), retrying in {}s...",
+                    backoff.as_secs()
+                );
+                clock.sleep(backoff);
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+                attempts += 1;
+            }
+        }
+    }
+}
+
+fn format_countdown(remaining: Duration) -> String {
+    let secs = remaining.as_secs();
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{cell::Cell, time::Duration};
+
+    use chrono::{TimeZone, Utc};
+
+    use super::{MAX_ATTEMPTS, format_countdown, wait_for_unlock, with_backoff};
+    #[cfg(test)]
+    use crate::template::clock::tests::FakeClock;
+    use crate::{day, template::clock::Clock, year};
+
+    #[test]
+    fn waits_until_unlock() {
+        let start = Utc.with_ymd_and_hms(2025, 12, 2, 4, 58, 30).unwrap();
+        let clock = FakeClock::at(start);
+
+        assert_eq!(wait_for_unlock(&clock), (year!(2025), day!(2)));
+        assert_eq!(
+            clock.now(),
+            Utc.with_ymd_and_hms(2025, 12, 2, 5, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn retries_with_backoff() {
+        let start = Utc.with_ymd_and_hms(2025, 12, 2, 5, 0, 0).unwrap();
+        let clock = FakeClock::at(start);
+        let calls = Cell::new(0);
+
+        let result = with_backoff(&clock, || {
+            calls.set(calls.get() + 1);
+            if calls.get() < 4 {
+                Err("not yet")
+            } else {
+                Ok(calls.get())
+            }
+        });
+
+        assert_eq!(result, Ok(4));
+        assert_eq!((clock.now() - start).num_seconds(), 1 + 2 + 4);
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let clock = FakeClock::at(Utc::now());
+        let calls = Cell::new(0);
+
+        let result: Result<(), _> = with_backoff(&clock, || {
+            calls.set(calls.get() + 1);
+            Err("not yet")
+        });
+
+        assert_eq!(result, Err("not yet"));
+        assert_eq!(calls.get(), MAX_ATTEMPTS);
+    }
+
+    #[test]
+    fn formats_countdown() {
+        assert_eq!(format_countdown(Duration::from_secs(59)), "00:00:59");
+        assert_eq!(format_countdown(Duration::from_secs(3_723)), "01:02:03");
+        assert_eq!(format_countdown(Duration::from_secs(30 * 3600)), "30:00:00");
+    }
+}
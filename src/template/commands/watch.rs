@@ -0,0 +1,209 @@
+use std::{
+    fs,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, Day, Year, config, paths,
+    render::{OutputFormat, PartResult},
+    run_multi::child_commands,
+    vault,
+};
+
+const ANSI_CLEAR_SCREEN: &str = "\x1b[2J";
+const ANSI_CURSOR_HOME: &str = "\x1b[H";
+
+/// How often the watched files are checked for modifications.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Editors often write a file in several steps, wait for them to finish before rebuilding.
+const SETTLE_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchMode {
+    /// Re-runs the solution, like `cargo solve`.
+    Solve,
+    /// Re-runs the tests of the day, like `cargo test --bin`.
+    Tests,
+}
+
+/// Re-runs the solution or the tests of `day` whenever its bin, the library or its data files
+/// are saved, until interrupted. Modification times are polled, which works on every platform.
+pub fn handle(year: Year, day: Day, release: bool, mode: WatchMode, format: OutputFormat) {
+    let watched = watched_files(year, day);
+    let mut previous: Option<Vec<PartResult>> = None;
+    let mut runs = 0;
+
+    loop {
+        let snapshot = modification_times(&watched);
+        runs += 1;
+
+        // only clear a terminal, and leave machine-readable output alone.
+        let ansi = matches!(format, OutputFormat::Ansi | OutputFormat::Plain)
+            && OutputFormat::detect() == OutputFormat::Ansi;
+        let (bold, reset) = if ansi {
+            print!("{ANSI_CLEAR_SCREEN}{ANSI_CURSOR_HOME}");
+            (ANSI_BOLD, ANSI_RESET)
+        } else {
+            ("", "")
+        };
+        println!(
+            "{bold}Watching {} (run #{runs}), press Ctrl-C to stop.{reset}\n",
+            paths::bin_name(year, day)
+        );
+
+        match mode {
+            WatchMode::Solve => {
+                if !vault::exists(paths::input_path(year, day)) {
+                    eprintln!("No input yet, run `cargo download {day}`.");
+                }
+                let mut renderer = format.renderer();
+                let results = child_commands::run_solution(
+                    year,
+                    day,
+                    false,
+                    release,
+                    None,
+                    renderer.as_mut(),
+                )
+                .unwrap_or_else(|e| {
+                    eprintln!("✖ {e}");
+                    vec![]
+                });
+                renderer.finish(None);
+
+                if let Some(previous) = &previous {
+                    println!();
+                    for line in diff(previous, &results) {
+                        println!("{line}");
+                    }
+                }
+                previous = Some(results);
+            }
+            WatchMode::Tests => {
+                let passed = run_tests(year, day, release);
+                println!(
+                    "\n{}",
+                    if passed {
+                        "✔ Tests passed."
+                    } else {
+                        "✖ Tests failed."
+                    }
+                );
+            }
+        }
+
+        while modification_times(&watched) == snapshot {
+            thread::sleep(POLL_INTERVAL);
+        }
+        thread::sleep(SETTLE_DELAY);
+    }
+}
+
+/// The bin of `day`, the library and the data files the solution and its tests read.
+fn watched_files(year: Year, day: Day) -> Vec<String> {
+    let input = paths::input_path(year, day);
+    let locked_input = vault::locked_path(&input).to_string_lossy().to_string();
+    vec![
+        paths::bin_path(year, day),
+        "src/lib.rs".into(),
+        input,
+        locked_input,
+        paths::example_path(year, day),
+        paths::example_part_path(year, day, 1),
+        paths::example_part_path(year, day, 2),
+    ]
+}
+
+/// Missing files are part of the snapshot, so that creating or deleting one triggers a run.
+fn modification_times(files: &[String]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|file| fs::metadata(file).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+fn run_tests(year: Year, day: Day, release: bool) -> bool {
+    let bin_name = paths::bin_name(year, day);
+    let mut args = vec!["test", "--quiet", "--bin", &bin_name];
+    if release {
+        args.push("--release");
+    }
+
+    let mut cmd = Command::new("cargo");
+    cmd.args(&args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit());
+    config::get().export(&mut cmd);
+    cmd.status().is_ok_and(|status| status.success())
+}
+
+/// Compares the answers of each part with the previous run, e.g. `Part 1: 42 → 43`.
+fn diff(previous: &[PartResult], current: &[PartResult]) -> Vec<String> {
+    let answer = |results: &[PartResult], part: u8| {
+        results
+            .iter()
+            .find(|result| result.part == part)
+            .and_then(|result| result.answer.clone())
+    };
+    let show = |answer: Option<String>| answer.unwrap_or_else(|| "✖".into());
+
+    let mut parts: Vec<u8> = previous
+        .iter()
+        .chain(current)
+        .map(|result| result.part)
+        .collect();
+    parts.sort_unstable();
+    parts.dedup();
+
+    if parts.is_empty() {
+        return vec!["No answers in either run.".into()];
+    }
+
+    parts
+        .into_iter()
+        .map(|part| {
+            let (before, after) = (answer(previous, part), answer(current, part));
+            if before == after {
+                format!("Part {part}: unchanged ({})", show(after))
+            } else {
+                format!("Part {part}: {} → {}", show(before), show(after))
+            }
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::diff;
+    use crate::{day, template::render::PartResult};
+
+    fn result(part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            day: day!(1),
+            part,
+            answer: answer.map(ToString::to_string),
+            duration: Duration::ZERO,
+            samples: 1,
+        }
+    }
+
+    #[test]
+    fn diffs_answers() {
+        assert_eq!(
+            diff(
+                &[result(1, Some("42")), result(2, None)],
+                &[result(1, Some("42")), result(2, Some("7"))]
+            ),
+            ["Part 1: unchanged (42)", "Part 2: ✖ → 7"]
+        );
+        assert_eq!(diff(&[result(1, Some("42"))], &[]), ["Part 1: 42 → ✖"]);
+        assert_eq!(diff(&[], &[]), ["No answers in either run."]);
+    }
+}
@@ -0,0 +1,700 @@
+//! Project configuration, read from `aoc.toml` at the project root.
+//!
+//! Every key can be overridden by an environment variable named after it, e.g. `bench.max_samples`
+//! by `AOC_BENCH_MAX_SAMPLES`, and on the command line with `--set bench.max_samples=100`.
+//! Values are strings, integers or booleans, at the top level or in one level of tables.
+use std::{
+    collections::BTreeMap,
+    env,
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::Path,
+    process::{self, Command},
+    str::FromStr,
+    sync::OnceLock,
+};
+
+use toml::de::{DeTable, DeValue};
+
+use crate::template::Year;
+
+/// Name of the configuration file, unless overridden with `--config` or `AOC_CONFIG`.
+/// It is looked up in the working directory and its parents, then in the project root.
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+/// All known keys, sections are separated by a dot.
+const KEYS: &[&str] = &[
+    "year",
+    "data_dir",
+    "backend",
+    "readme.path",
+    "readme.marker",
+    "bench.target_ms",
+    "bench.min_samples",
+    "bench.max_samples",
+    "submit.checks",
+    "submit.confirm",
+    "submit.wait",
+    "inputs.key_file",
+    "inputs.lint",
+];
+
+/// Keys holding paths, which are relative to the directory of the configuration file.
+const PATH_KEYS: &[&str] = &["data_dir", "readme.path", "inputs.key_file"];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// The year commands operate on, unless `--year` is passed.
+    pub year: Option<Year>,
+    /// The directory holding the data folders of each year.
+    pub data_dir: String,
+    pub backend: Backend,
+    pub readme: ReadmeConfig,
+    pub bench: BenchConfig,
+    pub submit: SubmitConfig,
+    pub inputs: InputsConfig,
+}
+
+/// Where `cargo time --store` writes the benchmark tables.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReadmeConfig {
+    pub path: String,
+    /// The marker enclosing the table of a year, `{year}` is replaced with the year.
+    pub marker: String,
+}
+
+/// How solutions are benchmarked with `--time`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// The approximate time spent benchmarking a part.
+    pub target_ms: u64,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
+
+/// Safety nets around submitting answers.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SubmitConfig {
+    /// Run the plausibility checks before submitting.
+    pub checks: bool,
+    /// Ask before `cargo submit` sends an answer.
+    pub confirm: bool,
+    /// Sleep through cooldowns as if `--wait` was passed.
+    pub wait: bool,
+}
+
+/// How inputs and examples are loaded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputsConfig {
+    /// Where the key of the encrypted inputs is read from, unless `AOC_INPUTS_KEY` is set.
+    pub key_file: String,
+    /// Warn about trailing whitespace and a missing trailing newline when loading a file.
+    pub lint: bool,
+}
+
+/// The tool used to talk to the Advent of Code server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    AocCli,
+}
+
+impl Backend {
+    const ALL: &[(&str, Self)] = &[("aoc-cli", Self::AocCli)];
+
+    /// The executable of the backend.
+    #[must_use]
+    pub const fn command(self) -> &'static str {
+        match self {
+            Self::AocCli => "aoc",
+        }
+    }
+}
+
+impl Display for Backend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = Self::ALL
+            .iter()
+            .find(|(_, backend)| backend == self)
+            .map_or("unknown", |(name, _)| name);
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Backend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|(name, _)| *name == s)
+            .map(|(_, backend)| *backend)
+            .ok_or_else(|| {
+                let names: Vec<_> = Self::ALL.iter().map(|(name, _)| *name).collect();
+                format!(
+                    "unknown backend `{s}`, expecting one of: {}.",
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: None,
+            data_dir: "data".into(),
+            backend: Backend::AocCli,
+            readme: ReadmeConfig {
+                path: "README.md".into(),
+                marker: "<!--- benchmarking table {year} --->".into(),
+            },
+            bench: BenchConfig {
+                target_ms: 1000,
+                min_samples: 10,
+                max_samples: 10000,
+            },
+            submit: SubmitConfig {
+                checks: true,
+                confirm: true,
+                wait: false,
+            },
+            inputs: InputsConfig {
+                key_file: ".aoc-key".into(),
+                lint: false,
+            },
+        }
+    }
+}
+
+/// A value as written in the configuration file, or as passed in the environment or command line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Value {
+    String(String),
+    Integer(i64),
+    Boolean(bool),
+    /// An untyped value from an environment variable or `--set`, parsed according to the key.
+    Text(String),
+}
+
+/// Where a value was set, so that errors can point at it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Origin {
+    File { path: String, line: usize },
+    Env(String),
+    Cli,
+}
+
+impl Display for Origin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File { path, line } => write!(f, "{path}:{line}"),
+            Self::Env(var) => write!(f, "environment variable `{var}`"),
+            Self::Cli => write!(f, "--set"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(String, io::Error),
+    Syntax {
+        origin: Origin,
+        message: String,
+    },
+    UnknownKey {
+        key: String,
+        origin: Origin,
+    },
+    InvalidValue {
+        key: String,
+        origin: Option<Origin>,
+        message: String,
+    },
+}
+
+impl Error for ConfigError {}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(path, e) => write!(f, "could not read \"{path}\": {e}"),
+            Self::Syntax { origin, message } => write!(f, "{origin}: {message}"),
+            Self::UnknownKey { key, origin } => write!(
+                f,
+                "{origin}: unknown key `{key}`, expecting one of: {}.",
+                KEYS.join(", ")
+            ),
+            Self::InvalidValue {
+                key,
+                origin: Some(origin),
+                message,
+            } => write!(f, "{origin}: invalid `{key}`: {message}"),
+            Self::InvalidValue {
+                key,
+                origin: None,
+                message,
+            } => write!(f, "invalid `{key}`: {message}"),
+        }
+    }
+}
+
+/// The name of the environment variable overriding `key`, e.g. `AOC_BENCH_MAX_SAMPLES`.
+#[must_use]
+pub fn env_var(key: &str) -> String {
+    format!("AOC_{}", key.replace('.', "_").to_uppercase())
+}
+
+impl Config {
+    /// Loads the configuration file at `path` (if present), then applies the environment
+    /// variables and the `overrides` passed on the command line, in that order.
+    /// Paths in the file are relative to its directory.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`ConfigError`] if the file could not be read, is malformed, or contains
+    /// an unknown key or an invalid value.
+    pub fn load(path: &str, overrides: &[String]) -> Result<Self, ConfigError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => Some(contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(ConfigError::Io(path.into(), e)),
+        };
+        Self::from_sources(
+            contents.as_deref().map(|contents| (path, contents)),
+            |var| env::var(var).ok(),
+            overrides,
+        )
+    }
+
+    fn from_sources(
+        file: Option<(&str, &str)>,
+        env: impl Fn(&str) -> Option<String>,
+        overrides: &[String],
+    ) -> Result<Self, ConfigError> {
+        let mut values: BTreeMap<String, (Value, Origin)> = BTreeMap::new();
+
+        if let Some((path, contents)) = file {
+            let dir = Path::new(path).parent().unwrap_or_else(|| Path::new(""));
+            for (key, value, line) in parse_toml(path, contents)? {
+                let origin = Origin::File {
+                    path: path.into(),
+                    line,
+                };
+                if !KEYS.contains(&key.as_str()) {
+                    return Err(ConfigError::UnknownKey { key, origin });
+                }
+                let value = match value {
+                    Value::String(s) if PATH_KEYS.contains(&key.as_str()) => {
+                        Value::String(dir.join(s).to_string_lossy().to_string())
+                    }
+                    value => value,
+                };
+                values.insert(key, (value, origin));
+            }
+        }
+
+        for key in KEYS {
+            let var = env_var(key);
+            if let Some(value) = env(&var) {
+                values.insert((*key).into(), (Value::Text(value), Origin::Env(var)));
+            }
+        }
+
+        for assignment in overrides {
+            let Some((key, value)) = assignment.split_once('=') else {
+                return Err(ConfigError::Syntax {
+                    origin: Origin::Cli,
+                    message: format!("expected `key=value`, got `{assignment}`."),
+                });
+            };
+            let key = key.trim();
+            if !KEYS.contains(&key) {
+                return Err(ConfigError::UnknownKey {
+                    key: key.into(),
+                    origin: Origin::Cli,
+                });
+            }
+            values.insert(key.into(), (Value::Text(value.trim().into()), Origin::Cli));
+        }
+
+        let mut config = Self::default();
+        for (key, (value, origin)) in values {
+            config
+                .set(&key, value)
+                .map_err(|message| ConfigError::InvalidValue {
+                    key,
+                    origin: Some(origin),
+                    message,
+                })?;
+        }
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn set(&mut self, key: &str, value: Value) -> Result<(), String> {
+        match key {
+            "year" => {
+                let year = match value {
+                    Value::Integer(year) => year.to_string(),
+                    Value::String(year) | Value::Text(year) => year,
+                    Value::Boolean(_) => return Err("expected a year.".into()),
+                };
+                self.year = Some(year.parse().map_err(|e| format!("{e}."))?);
+            }
+            "data_dir" => self.data_dir = string(value)?,
+            "backend" => self.backend = string(value)?.parse()?,
+            "readme.path" => self.readme.path = string(value)?,
+            "readme.marker" => self.readme.marker = string(value)?,
+            "bench.target_ms" => self.bench.target_ms = integer(value)?,
+            "bench.min_samples" => self.bench.min_samples = integer(value)?,
+            "bench.max_samples" => self.bench.max_samples = integer(value)?,
+            "submit.checks" => self.submit.checks = boolean(value)?,
+            "submit.confirm" => self.submit.confirm = boolean(value)?,
+            "submit.wait" => self.submit.wait = boolean(value)?,
+            "inputs.key_file" => self.inputs.key_file = string(value)?,
+            "inputs.lint" => self.inputs.lint = boolean(value)?,
+            _ => unreachable!("keys are checked against `KEYS`"),
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        let invalid = |key: &str, message: &str| {
+            Err(ConfigError::InvalidValue {
+                key: key.into(),
+                origin: None,
+                message: message.into(),
+            })
+        };
+
+        if self.data_dir.is_empty() {
+            return invalid("data_dir", "expected a directory.");
+        }
+        if !self.readme.marker.contains("{year}") {
+            return invalid(
+                "readme.marker",
+                "expected a `{year}` placeholder, each year has its own table.",
+            );
+        }
+        if self.bench.target_ms == 0 {
+            return invalid("bench.target_ms", "expected a positive duration.");
+        }
+        if self.bench.min_samples == 0 {
+            return invalid("bench.min_samples", "expected at least one sample.");
+        }
+        if self.bench.max_samples < self.bench.min_samples {
+            return invalid(
+                "bench.max_samples",
+                "expected at least as many samples as `bench.min_samples`.",
+            );
+        }
+        Ok(())
+    }
+
+    /// The marker enclosing the benchmark table of `year` in the README.
+    #[must_use]
+    #[allow(clippy::literal_string_with_formatting_args)]
+    pub fn readme_marker(&self, year: Year) -> String {
+        self.readme.marker.replace("{year}", &year.to_string())
+    }
+
+    /// Passes the resolved configuration to a child process, so that it sees the same
+    /// values including overrides from the command line.
+    pub fn export(&self, cmd: &mut Command) {
+        let values = [
+            ("year", self.year.map(|year| year.to_string())),
+            ("data_dir", Some(self.data_dir.clone())),
+            ("backend", Some(self.backend.to_string())),
+            ("readme.path", Some(self.readme.path.clone())),
+            ("readme.marker", Some(self.readme.marker.clone())),
+            ("bench.target_ms", Some(self.bench.target_ms.to_string())),
+            (
+                "bench.min_samples",
+                Some(self.bench.min_samples.to_string()),
+            ),
+            (
+                "bench.max_samples",
+                Some(self.bench.max_samples.to_string()),
+            ),
+            ("submit.checks", Some(self.submit.checks.to_string())),
+            ("submit.confirm", Some(self.submit.confirm.to_string())),
+            ("submit.wait", Some(self.submit.wait.to_string())),
+            ("inputs.key_file", Some(self.inputs.key_file.clone())),
+            ("inputs.lint", Some(self.inputs.lint.to_string())),
+        ];
+        for (key, value) in values {
+            if let Some(value) = value {
+                cmd.env(env_var(key), value);
+            }
+        }
+    }
+}
+
+fn string(value: Value) -> Result<String, String> {
+    match value {
+        Value::String(s) | Value::Text(s) => Ok(s),
+        _ => Err("expected a string.".into()),
+    }
+}
+
+fn integer<T: TryFrom<i64> + FromStr>(value: Value) -> Result<T, String> {
+    let error = || "expected a positive integer.".to_string();
+    match value {
+        Value::Integer(n) => T::try_from(n).map_err(|_| error()),
+        Value::Text(s) => s.parse().map_err(|_| error()),
+        _ => Err(error()),
+    }
+}
+
+fn boolean(value: Value) -> Result<bool, String> {
+    match value {
+        Value::Boolean(b) => Ok(b),
+        Value::Text(s) if matches!(s.as_str(), "true" | "1") => Ok(true),
+        Value::Text(s) if matches!(s.as_str(), "false" | "0") => Ok(false),
+        _ => Err("expected `true` or `false`.".into()),
+    }
+}
+
+/// Parses the configuration file into `(key, value, line)` triples. Keys of a table are
+/// prefixed with its name, e.g. `bench.max_samples`.
+fn parse_toml(path: &str, contents: &str) -> Result<Vec<(String, Value, usize)>, ConfigError> {
+    let line = |offset: usize| contents[..offset].matches('\n').count() + 1;
+    let syntax = |offset: usize, message: String| ConfigError::Syntax {
+        origin: Origin::File {
+            path: path.into(),
+            line: line(offset),
+        },
+        message,
+    };
+
+    let table = DeTable::parse(contents).map_err(|e| {
+        syntax(
+            e.span().map_or(0, |span| span.start),
+            e.message().trim_end().into(),
+        )
+    })?;
+
+    let mut entries = vec![];
+    for (key, value) in table.get_ref() {
+        if let DeValue::Table(section) = value.get_ref() {
+            for (name, value) in section {
+                let key = format!("{}.{}", key.get_ref(), name.get_ref());
+                entries.push((key, value.get_ref(), value.span().start));
+            }
+        } else {
+            entries.push((
+                key.get_ref().to_string(),
+                value.get_ref(),
+                value.span().start,
+            ));
+        }
+    }
+
+    entries
+        .into_iter()
+        .map(|(key, value, offset)| {
+            let value = match value {
+                DeValue::String(s) => Value::String(s.to_string()),
+                DeValue::Boolean(b) => Value::Boolean(*b),
+                DeValue::Integer(n) => i64::from_str_radix(n.as_str(), n.radix())
+                    .map(Value::Integer)
+                    .map_err(|e| syntax(offset, format!("invalid integer: {e}.")))?,
+                value => {
+                    return Err(syntax(
+                        offset,
+                        format!(
+                            "unsupported {} value, expected a string, integer or boolean.",
+                            value.type_str()
+                        ),
+                    ));
+                }
+            };
+            Ok((key, value, line(offset)))
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+static CONFIG_FILE: OnceLock<String> = OnceLock::new();
+
+/// Loads the configuration, applying `overrides` from the command line.
+/// Must be called before the first call to [`get`] for the overrides to take effect.
+///
+/// # Errors
+///
+/// Will return [`ConfigError`] if the configuration could not be loaded, see [`Config::load`].
+pub fn init(path: Option<&str>, overrides: &[String]) -> Result<&'static Config, ConfigError> {
+    let path = path
+        .map(ToString::to_string)
+        .or_else(|| env::var("AOC_CONFIG").ok())
+        .unwrap_or_else(find);
+    let config = Config::load(&path, overrides)?;
+    CONFIG_FILE.get_or_init(|| path);
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+/// Finds the configuration file in the working directory or one of its parents, so that
+/// commands run from a subdirectory see the same file. Binaries run outside of the project,
+/// e.g. with embedded inputs, fall back to the file in the project root.
+fn find() -> String {
+    let parent = env::current_dir().ok().and_then(|dir| {
+        dir.ancestors()
+            .skip(1)
+            .map(|dir| dir.join(CONFIG_PATH))
+            .find(|path| path.is_file())
+    });
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join(CONFIG_PATH);
+
+    if Path::new(CONFIG_PATH).is_file() {
+        CONFIG_PATH.into()
+    } else if let Some(path) = parent {
+        path.to_string_lossy().to_string()
+    } else if root.is_file() {
+        root.to_string_lossy().to_string()
+    } else {
+        CONFIG_PATH.into()
+    }
+}
+
+/// Returns the path of the configuration file, which might not exist.
+pub fn path() -> &'static str {
+    get();
+    CONFIG_FILE.get().map_or(CONFIG_PATH, String::as_str)
+}
+
+/// Returns the configuration, loading it on first use.
+///
+/// Exits the process if the configuration is invalid.
+pub fn get() -> &'static Config {
+    if let Some(config) = CONFIG.get() {
+        return config;
+    }
+    init(None, &[]).unwrap_or_else(|e| {
+        eprintln!("Invalid configuration: {e}");
+        process::exit(1);
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Backend, Config, ConfigError};
+    use crate::year;
+
+    fn load(file: &str, env: &[(&str, &str)], overrides: &[&str]) -> Result<Config, ConfigError> {
+        let overrides: Vec<String> = overrides.iter().map(ToString::to_string).collect();
+        Config::from_sources(
+            Some(("aoc.toml", file)),
+            |var| {
+                env.iter()
+                    .find(|(name, _)| *name == var)
+                    .map(|(_, value)| (*value).to_string())
+            },
+            &overrides,
+        )
+    }
+
+    #[test]
+    fn defaults_without_file() {
+        let config = Config::from_sources(None, |_| None, &[]).unwrap();
+        assert_eq!(config, Config::default());
+    }
+
+    #[test]
+    fn parses_file() {
+        let config = load(
+            r#"
+# the year commands operate on.
+year = 2024
+data_dir = "puzzles" # trailing comment
+
+[bench]
+target_ms = 2_000
+
+[submit]
+confirm = false
+
+[readme]
+marker = '<!-- # {year} -->' # the `#` is part of the string
+"#,
+            &[],
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(year!(2024)));
+        assert_eq!(config.data_dir, "puzzles");
+        assert_eq!(config.backend, Backend::AocCli);
+        assert_eq!(config.bench.target_ms, 2000);
+        assert!(!config.submit.confirm);
+        assert!(config.submit.checks);
+        assert_eq!(config.readme.marker, "<!-- # {year} -->");
+    }
+
+    #[test]
+    fn resolves_paths_against_the_file() {
+        let config = Config::from_sources(
+            Some(("../aoc.toml", "data_dir = \"puzzles\"\n")),
+            |var| (var == "AOC_INPUTS_KEY_FILE").then(|| ".key".into()),
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(config.data_dir, "../puzzles");
+        assert_eq!(config.inputs.key_file, ".key");
+    }
+
+    #[test]
+    fn env_and_cli_override_file() {
+        let config = load(
+            "year = 2024\n[bench]\nmax_samples = 50\n",
+            &[("AOC_YEAR", "2023"), ("AOC_BENCH_MAX_SAMPLES", "20")],
+            &["bench.max_samples=30"],
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(year!(2023)));
+        assert_eq!(config.bench.max_samples, 30);
+    }
+
+    #[test]
+    fn errors_name_the_offending_key() {
+        let error = |file, env: &[(&str, &str)], overrides: &[&str]| {
+            load(file, env, overrides).unwrap_err().to_string()
+        };
+
+        assert_eq!(
+            error("[submit]\nwait = \"yes\"\n", &[], &[]),
+            "aoc.toml:2: invalid `submit.wait`: expected `true` or `false`."
+        );
+        assert_eq!(
+            error("", &[("AOC_YEAR", "1999")], &[]),
+            "environment variable `AOC_YEAR`: invalid `year`: expecting a year of 2015 or later."
+        );
+        assert_eq!(
+            error("", &[], &["backend=curl"]),
+            "--set: invalid `backend`: unknown backend `curl`, expecting one of: aoc-cli."
+        );
+        assert!(
+            error("[bench]\nsamples = 3\n", &[], &[])
+                .starts_with("aoc.toml:2: unknown key `bench.samples`")
+        );
+        assert_eq!(
+            error("[bench]\nmin_samples = 20\nmax_samples = 10\n", &[], &[]),
+            "invalid `bench.max_samples`: expected at least as many samples as `bench.min_samples`."
+        );
+        assert_eq!(
+            error("data_dir = data\n", &[], &[]),
+            "aoc.toml:1: string values must be quoted, expected literal string"
+        );
+        assert_eq!(
+            error("\n[bench]\ntarget_ms = [1, 2]\n", &[], &[]),
+            "aoc.toml:3: unsupported array value, expected a string, integer or boolean."
+        );
+    }
+}
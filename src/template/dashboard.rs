@@ -0,0 +1,313 @@
+//! A full-screen dashboard of days and parts, drawn with plain ANSI escape sequences.
+//!
+//! The whole grid is redrawn from the top-left corner whenever a result arrives.
+//! When the run finishes, the cursor is restored below the final state so it stays on screen.
+
+use std::{
+    fmt::Write as _,
+    io::{Write, stdout},
+    time::Duration,
+};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day,
+    render::{PartResult, Renderer},
+};
+
+const ANSI_CLEAR_SCREEN: &str = "\x1b[2J";
+const ANSI_CURSOR_HOME: &str = "\x1b[H";
+const ANSI_CLEAR_LINE: &str = "\x1b[K";
+const ANSI_CLEAR_BELOW: &str = "\x1b[J";
+const ANSI_HIDE_CURSOR: &str = "\x1b[?25l";
+const ANSI_SHOW_CURSOR: &str = "\x1b[?25h";
+
+const ANSWER_WIDTH: usize = 16;
+const BAR_WIDTH: usize = 10;
+const LOG_LINES: usize = 5;
+
+#[derive(Clone, Debug, Default)]
+enum Cell {
+    #[default]
+    Pending,
+    Running,
+    Benching {
+        answer: Option<String>,
+        done: u128,
+        iterations: u128,
+    },
+    Done {
+        answer: Option<String>,
+        duration: Duration,
+    },
+    NotSolved,
+    /// The solution did not build or exited with an error.
+    Failed,
+    Skipped,
+}
+
+struct Row {
+    day: Day,
+    parts: [Cell; 2],
+}
+
+#[derive(Default)]
+pub struct DashboardRenderer {
+    rows: Vec<Row>,
+    log: Vec<String>,
+    total_millis: Option<f64>,
+    started: bool,
+}
+
+impl DashboardRenderer {
+    fn row(&mut self, day: Day) -> &mut Row {
+        if !self.rows.iter().any(|row| row.day == day) {
+            self.rows.push(Row {
+                day,
+                parts: Default::default(),
+            });
+            self.rows.sort_unstable_by_key(|row| row.day);
+        }
+        // NOTE: the row has been inserted above if it was missing.
+        self.rows.iter_mut().find(|row| row.day == day).unwrap()
+    }
+
+    fn cell(&mut self, day: Day, part: u8) -> Option<&mut Cell> {
+        let index = usize::from(part).checked_sub(1)?;
+        self.row(day).parts.get_mut(index)
+    }
+
+    fn draw(&mut self) {
+        let mut out = String::new();
+
+        if !self.started {
+            self.started = true;
+            out.push_str(ANSI_HIDE_CURSOR);
+            out.push_str(ANSI_CLEAR_SCREEN);
+        }
+
+        out.push_str(ANSI_CURSOR_HOME);
+
+        // a cell is a symbol, the answer and the detail column, separated by spaces.
+        let width = ANSWER_WIDTH + BAR_WIDTH + 3;
+        let header = format!("{:<width$}", "Part 1");
+        let _ = writeln!(
+            out,
+            "{ANSI_BOLD}Day  {header} Part 2{ANSI_RESET}{ANSI_CLEAR_LINE}"
+        );
+
+        for row in &self.rows {
+            let _ = writeln!(
+                out,
+                "{}   {} {}{ANSI_CLEAR_LINE}",
+                row.day,
+                draw_cell(&row.parts[0]),
+                draw_cell(&row.parts[1])
+            );
+        }
+
+        if let Some(total_millis) = self.total_millis {
+            let _ = writeln!(out, "{ANSI_CLEAR_LINE}");
+            let _ = writeln!(
+                out,
+                "{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}{ANSI_CLEAR_LINE}"
+            );
+        }
+
+        if !self.log.is_empty() {
+            let _ = writeln!(out, "{ANSI_CLEAR_LINE}");
+            for line in &self.log[self.log.len().saturating_sub(LOG_LINES)..] {
+                let _ = writeln!(out, "{ANSI_ITALIC}{line}{ANSI_RESET}{ANSI_CLEAR_LINE}");
+            }
+        }
+
+        out.push_str(ANSI_CLEAR_BELOW);
+
+        let mut stdout = stdout();
+        let _ = stdout.write_all(out.as_bytes());
+        let _ = stdout.flush();
+    }
+}
+
+/// Formats a cell as a fixed-width status symbol, answer and time or progress bar.
+fn draw_cell(cell: &Cell) -> String {
+    let (symbol, answer, detail) = match cell {
+        Cell::Pending => ("·", None, String::new()),
+        Cell::Running => ("…", None, String::new()),
+        Cell::Benching {
+            answer,
+            done,
+            iterations,
+        } => ("⏱", answer.as_deref(), progress_bar(*done, *iterations)),
+        Cell::Done {
+            answer: Some(answer),
+            duration,
+        } => ("✔", Some(answer.as_str()), format!("{duration:.1?}")),
+        Cell::Done { answer: None, .. } | Cell::NotSolved => ("✖", None, String::new()),
+        Cell::Failed => ("✖", Some("failed"), String::new()),
+        Cell::Skipped => ("–", None, String::new()),
+    };
+
+    format!(
+        "{symbol} {:<ANSWER_WIDTH$} {detail:<BAR_WIDTH$}",
+        truncate(answer)
+    )
+}
+
+fn truncate(answer: Option<&str>) -> String {
+    let Some(answer) = answer else {
+        return String::new();
+    };
+
+    if answer.contains('\n') {
+        return "▼ (multi-line)".into();
+    }
+
+    if answer.chars().count() > ANSWER_WIDTH {
+        let mut truncated: String = answer.chars().take(ANSWER_WIDTH - 1).collect();
+        truncated.push('…');
+        truncated
+    } else {
+        answer.into()
+    }
+}
+
+fn progress_bar(done: u128, iterations: u128) -> String {
+    let filled = (done * BAR_WIDTH as u128)
+        .checked_div(iterations)
+        .map_or(0, |x| usize::try_from(x).unwrap_or(BAR_WIDTH))
+        .min(BAR_WIDTH);
+    format!("{}{}", "█".repeat(filled), "░".repeat(BAR_WIDTH - filled))
+}
+
+impl Renderer for DashboardRenderer {
+    fn start(&mut self, days: &[Day]) {
+        for day in days {
+            self.row(*day);
+        }
+        self.draw();
+    }
+
+    fn day_started(&mut self, day: Day) {
+        self.row(day).parts = [Cell::Running, Cell::Running];
+        self.draw();
+    }
+
+    fn part_answered(&mut self, result: &PartResult) {
+        if let Some(cell) = self.cell(result.day, result.part) {
+            *cell = Cell::Benching {
+                answer: result.answer.clone(),
+                done: 0,
+                iterations: 0,
+            };
+        }
+        self.draw();
+    }
+
+    fn bench_started(&mut self, day: Day, part: u8, iterations: u128) {
+        self.bench_progress(day, part, 0, iterations);
+    }
+
+    fn bench_progress(&mut self, day: Day, part: u8, done: u128, iterations: u128) {
+        if let Some(Cell::Benching {
+            done: cell_done,
+            iterations: cell_iterations,
+            ..
+        }) = self.cell(day, part)
+        {
+            *cell_done = done;
+            *cell_iterations = iterations;
+        }
+        self.draw();
+    }
+
+    fn part_finished(&mut self, result: &PartResult) {
+        if let Some(cell) = self.cell(result.day, result.part) {
+            *cell = Cell::Done {
+                answer: result.answer.clone(),
+                duration: result.duration,
+            };
+        }
+        self.draw();
+    }
+
+    fn day_finished(&mut self, day: Day, solved: bool) {
+        // parts that never reported a result on a successful run were skipped,
+        // e.g. the second part of the final day.
+        for cell in &mut self.row(day).parts {
+            if matches!(cell, Cell::Pending | Cell::Running) {
+                *cell = if solved {
+                    Cell::Skipped
+                } else {
+                    Cell::NotSolved
+                };
+            }
+        }
+        self.draw();
+    }
+
+    fn day_failed(&mut self, day: Day, error: &str) {
+        for cell in &mut self.row(day).parts {
+            if matches!(cell, Cell::Pending | Cell::Running | Cell::Benching { .. }) {
+                *cell = Cell::Failed;
+            }
+        }
+        self.log.push(format!("Day {day}: {error}"));
+        self.draw();
+    }
+
+    fn finish(&mut self, total_millis: Option<f64>) {
+        self.total_millis = total_millis;
+        self.draw();
+        print!("{ANSI_SHOW_CURSOR}");
+        let _ = stdout().flush();
+    }
+
+    fn passthrough(&mut self, line: &str) {
+        self.log.push(line.to_string());
+        self.draw();
+    }
+
+    fn diagnostic(&mut self, line: &str) {
+        self.passthrough(line);
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Cell, draw_cell, progress_bar, truncate};
+
+    #[test]
+    fn draws_progress_bars() {
+        assert_eq!(progress_bar(0, 100), "░░░░░░░░░░");
+        assert_eq!(progress_bar(50, 100), "█████░░░░░");
+        assert_eq!(progress_bar(100, 100), "██████████");
+        assert_eq!(progress_bar(0, 0), "░░░░░░░░░░");
+    }
+
+    #[test]
+    fn truncates_long_answers() {
+        assert_eq!(truncate(Some("42")), "42");
+        assert_eq!(truncate(Some("12345678901234567890")), "123456789012345…");
+        assert_eq!(truncate(Some("#.\n.#")), "▼ (multi-line)");
+        assert_eq!(truncate(None), "");
+    }
+
+    #[test]
+    fn draws_cells_with_fixed_width() {
+        let done = Cell::Done {
+            answer: Some("42".into()),
+            duration: Duration::from_micros(15),
+        };
+        assert_eq!(
+            draw_cell(&done).chars().count(),
+            draw_cell(&Cell::Pending).chars().count()
+        );
+        assert!(draw_cell(&done).starts_with("✔ 42 "));
+        assert_eq!(
+            draw_cell(&Cell::Skipped).chars().count(),
+            draw_cell(&Cell::Pending).chars().count()
+        );
+    }
+}
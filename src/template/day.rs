@@ -1,14 +1,26 @@
+use std::collections::HashSet;
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::template::Year;
+
+#[cfg(feature = "today")]
+use chrono::Utc;
+
 #[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
+use crate::template::clock;
 
 #[cfg(feature = "today")]
-const SERVER_UTC_OFFSET: i32 = -5;
+/// The UTC offset of the Advent of Code server, in hours.
+pub const SERVER_UTC_OFFSET: i32 = -5;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 12).
+/// The highest day number of any year's calendar.
+const MAX_DAY: u8 = 25;
+
+/// A valid day number of advent (i.e. an integer in range 1 to 25).
+///
+/// Whether a day is part of a given year's calendar is checked with [`Year::contains`].
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -26,7 +38,7 @@ impl Day {
     /// returns [`None`] otherwise.
     #[must_use]
     pub const fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 12 {
+        if day == 0 || day > MAX_DAY {
             return None;
         }
         Some(Self(day))
@@ -37,19 +49,28 @@ impl Day {
     pub const fn into_inner(self) -> u8 {
         self.0
     }
+
+    /// Parses a [`Day`] and checks that it is part of `year`'s calendar.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`DayFromStrError`] if `s` is not a day number of `year`.
+    pub fn parse(s: &str, year: Year) -> Result<Self, DayFromStrError> {
+        let day: Self = s.parse()?;
+        if year.contains(day) {
+            Ok(day)
+        } else {
+            Err(DayFromStrError::NotInCalendar(day, year))
+        }
+    }
 }
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 12th of december, `None` otherwise.
+    /// Returns the current day if it's part of this year's advent calendar, `None` otherwise.
+    #[must_use]
     pub fn today() -> Option<Self> {
-        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 12 {
-            Self::new(u8::try_from(today.day()).ok()?)
-        } else {
-            None
-        }
+        clock::puzzle_at(Utc::now()).map(|(_, day)| day)
     }
 }
 
@@ -77,41 +98,163 @@ impl FromStr for Day {
     type Err = DayFromStrError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        let day: u8 = s
+            .trim()
+            .parse()
+            .map_err(|_| DayFromStrError::NotANumber(s.to_string()))?;
+        Self::new(day).ok_or(DayFromStrError::OutOfRange(day))
     }
 }
 
-/// An error which can be returned when parsing a [`Day`].
-#[derive(Debug)]
-pub struct DayFromStrError;
+/// An error which can be returned when parsing a [`Day`] or a [`DaySet`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum DayFromStrError {
+    Empty,
+    NotANumber(String),
+    OutOfRange(u8),
+    NotInCalendar(Day, Year),
+    ReversedRange(Day, Day),
+}
 
 impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 12")
+        match self {
+            Self::Empty => f.write_str("empty day selection"),
+            Self::NotANumber(s) => write!(f, "\"{s}\" is not a day number"),
+            Self::OutOfRange(day) => write!(f, "day {day} is out of range (1 to {MAX_DAY})"),
+            Self::NotInCalendar(day, year) => write!(
+                f,
+                "day {day} is not part of {year}, which has {} days",
+                year.last_day()
+            ),
+            Self::ReversedRange(start, end) => {
+                write!(f, "range {start}-{end} ends before it starts")
+            }
+        }?;
+        f.write_str(
+            ", expecting a day number, a range (`3-7`, `-5`, `11-`) \
+            or a comma-separated list of those (`1-5,8,11-`)",
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A set of days, usually parsed from a selection expression with [`DaySet::parse`].
+///
+/// The expression is a comma-separated list of day numbers and inclusive ranges.
+/// Ranges may be open on either side, e.g. `-5` or `11-`.
+///
+/// ```
+/// # use advent_of_code::{template::DaySet, year};
+/// let days = DaySet::parse("1-3,8,11-", year!(2025)).unwrap();
+/// assert_eq!(days.len(), 6);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DaySet(HashSet<Day>);
+
+impl DaySet {
+    /// Returns a set containing every day of advent in `year`.
+    #[must_use]
+    pub fn all(year: Year) -> Self {
+        Self(year.days().collect())
+    }
+
+    /// Parses a selection expression. Open ranges extend to the last day of `year`.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`DayFromStrError`] if the expression is malformed or refers to days
+    /// that are not part of `year`'s calendar.
+    pub fn parse(s: &str, year: Year) -> Result<Self, DayFromStrError> {
+        let mut days = HashSet::new();
+
+        for item in s.split(',').map(str::trim) {
+            if item.is_empty() {
+                return Err(DayFromStrError::Empty);
+            }
+
+            let Some((start, end)) = item.split_once('-') else {
+                days.insert(Day::parse(item, year)?);
+                continue;
+            };
+
+            let start = match start.trim() {
+                "" => Day(1),
+                start => Day::parse(start, year)?,
+            };
+            let end = match end.trim() {
+                "" => Day(year.last_day()),
+                end => Day::parse(end, year)?,
+            };
+
+            if end < start {
+                return Err(DayFromStrError::ReversedRange(start, end));
+            }
+
+            days.extend(year.days().filter(|day| *day >= start && *day <= end));
+        }
+
+        Ok(Self(days))
+    }
+
+    #[must_use]
+    pub fn contains(&self, day: Day) -> bool {
+        self.0.contains(&day)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Converts the [`DaySet`] into the underlying [`HashSet`].
+    #[must_use]
+    pub fn into_inner(self) -> HashSet<Day> {
+        self.0
+    }
+}
+
+impl From<HashSet<Day>> for DaySet {
+    fn from(value: HashSet<Day>) -> Self {
+        Self(value)
+    }
+}
+
+impl FromIterator<Day> for DaySet {
+    fn from_iter<T: IntoIterator<Item = Day>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 12th.
+/// An iterator that yields every day of advent in `year`, from the 1st to the last day of its calendar.
 #[must_use]
-pub const fn all_days() -> AllDays {
-    AllDays::new()
+pub const fn all_days(year: Year) -> AllDays {
+    AllDays::new(year)
 }
 
-/// An iterator that yields every day of advent from the 1st to the 12th.
+/// An iterator that yields every day of advent in a year, from the 1st to the last day of its calendar.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
     #[must_use]
-    pub const fn new() -> Self {
-        Self { current: 1 }
+    pub const fn new(year: Year) -> Self {
+        Self {
+            current: 1,
+            last: year.last_day(),
+        }
     }
 }
 
@@ -119,10 +262,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 12 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 12.
+        // NOTE: the iterator starts at 1 and the last day of a calendar is never above the highest day number.
         let day = Day(self.current);
         self.current += 1;
 
@@ -138,7 +281,7 @@ macro_rules! day {
     ($day:expr) => {
         const {
             $crate::template::Day::new($day)
-                .expect("invalid day number, expecting a value between 1 and 12")
+                .expect("invalid day number, expecting a value between 1 and 25")
         }
     };
 }
@@ -147,11 +290,14 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{Day, all_days};
+    use super::{Day, DayFromStrError, DaySet, all_days};
+    use crate::{template::Year, year};
+
+    const YEAR: Year = year!(2025);
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days(YEAR);
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -167,6 +313,60 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(12)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_iterator_for_long_calendars() {
+        assert_eq!(all_days(year!(2024)).last(), Some(Day(25)));
+    }
+
+    #[test]
+    fn parses_days_of_a_calendar() {
+        assert_eq!(Day::parse("12", YEAR), Ok(Day(12)));
+        assert_eq!(Day::parse("20", year!(2024)), Ok(Day(20)));
+        assert_eq!(
+            Day::parse("20", YEAR),
+            Err(DayFromStrError::NotInCalendar(Day(20), YEAR))
+        );
+    }
+
+    #[test]
+    fn parses_day_set_expressions() {
+        let days = DaySet::parse("1-3,8,11-", YEAR).unwrap();
+        assert_eq!(days.len(), 6);
+        assert!(days.contains(Day(1)));
+        assert!(days.contains(Day(3)));
+        assert!(!days.contains(Day(4)));
+        assert!(days.contains(Day(8)));
+        assert!(days.contains(Day(12)));
+
+        let days = DaySet::parse("-2, 5", YEAR).unwrap();
+        assert_eq!(days.into_inner().len(), 3);
+
+        let days = DaySet::parse("20-", year!(2024)).unwrap();
+        assert_eq!(days.len(), 6);
+    }
+
+    #[test]
+    fn rejects_invalid_day_set_expressions() {
+        assert_eq!(DaySet::parse("", YEAR), Err(DayFromStrError::Empty));
+        assert_eq!(DaySet::parse("1,,2", YEAR), Err(DayFromStrError::Empty));
+        assert_eq!(
+            DaySet::parse("26", year!(2024)),
+            Err(DayFromStrError::OutOfRange(26))
+        );
+        assert_eq!(
+            DaySet::parse("10-13", YEAR),
+            Err(DayFromStrError::NotInCalendar(Day(13), YEAR))
+        );
+        assert_eq!(
+            DaySet::parse("5-3", YEAR),
+            Err(DayFromStrError::ReversedRange(Day(5), Day(3)))
+        );
+        assert_eq!(
+            DaySet::parse("a-3", YEAR),
+            Err(DayFromStrError::NotANumber("a".into()))
+        );
+    }
 }
 
 /* -------------------------------------------------------------------------- */
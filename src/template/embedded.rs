@@ -0,0 +1,44 @@
+//! Inputs and examples embedded into the binaries at compile time with the `embed` feature.
+//!
+//! The lookup is generated by `build.rs` from the plaintext files present at build time, files
+//! added later are read from the filesystem. Each binary evaluates the lookup of its own day at
+//! compile time in [`solution!`](crate::solution) and registers it, so it only carries that day's
+//! files. Embedded binaries run from any working directory.
+use std::sync::OnceLock;
+
+use crate::template::{Day, Year};
+
+include!(concat!(env!("OUT_DIR"), "/embedded.rs"));
+
+/// The embedded files of a day, keyed by their folder and name, e.g. `inputs/01.txt`.
+pub type Files = &'static [(&'static str, &'static str)];
+
+static REGISTERED: OnceLock<Files> = OnceLock::new();
+
+/// The files embedded for `day`. Evaluate it in a `const` block, calling it at run time
+/// would link the files of every day into the binary.
+#[must_use]
+pub const fn files(year: Year, day: Day) -> Files {
+    day_files(year.into_inner(), day.into_inner())
+}
+
+/// Makes `files` available to [`get`], called by `solution!` before the input is read.
+pub fn register(files: Files) {
+    let _ = REGISTERED.set(files);
+}
+
+/// Returns the embedded contents of the data file at `path`, e.g. `data/2025/inputs/01.txt`.
+///
+/// Only the folder and the name of the file are compared, so the lookup does not depend on
+/// the data directory configured where the binary runs.
+#[must_use]
+pub fn get(path: &str) -> Option<&'static str> {
+    let mut components = path.rsplit(['/', '\\']);
+    let name = components.next()?;
+    let folder = components.next()?;
+    REGISTERED
+        .get()?
+        .iter()
+        .find(|(key, _)| key.split_once('/') == Some((folder, name)))
+        .map(|(_, contents)| *contents)
+}
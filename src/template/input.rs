@@ -0,0 +1,241 @@
+//! Loading puzzle inputs and examples from the data folders.
+//!
+//! Files are normalised on load, so that solutions only ever see `\n` line endings: inputs
+//! copied on Windows or saved by some editors carry CRLF line endings and byte order marks.
+use std::{fmt::Display, io};
+
+use crate::template::{
+    Day, config,
+    vault::{self, VaultError},
+};
+
+/// The UTF-8 encoded byte order mark.
+const BOM: &[u8] = b"\xef\xbb\xbf";
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing {
+        path: String,
+        folder: String,
+        day: Day,
+    },
+    /// Only inputs must not be empty, a scaffolded example is empty until it is filled in.
+    Empty {
+        path: String,
+        day: Day,
+    },
+    NotUtf8(String),
+    Unreadable(VaultError),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { path, folder, day } => {
+                write!(f, "\"{path}\" does not exist.")?;
+                match folder.as_str() {
+                    "examples" => write!(
+                        f,
+                        " Run `cargo extract-example {day}` or paste the example into it."
+                    ),
+                    "inputs" | "puzzles" => write!(f, " Run `cargo download {day}` first."),
+                    _ => Ok(()),
+                }
+            }
+            Self::Empty { path, day } => write!(
+                f,
+                "\"{path}\" is empty. Run `cargo download {day}` to fetch the input again."
+            ),
+            Self::NotUtf8(path) => write!(
+                f,
+                "\"{path}\" is not valid UTF-8, use `solution!(.., bytes)` to read it as bytes."
+            ),
+            Self::Unreadable(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Reads the data file of `day` at `path`, which belongs to `folder`, and normalises it.
+///
+/// # Errors
+///
+/// Will return [`InputError`] if the file could not be read, see [`load_bytes`], or is not UTF-8.
+pub fn load(path: &str, folder: &str, day: Day) -> Result<String, InputError> {
+    utf8(path, load_bytes(path, folder, day)?)
+}
+
+/// Like [`load`], for a data file found in the data directory, which is embedded with the
+/// `embed` feature. The file passed with `--input` is always read from the filesystem.
+///
+/// # Errors
+///
+/// Will return [`InputError`] if the file could not be read, see [`load_bytes`], or is not UTF-8.
+pub fn load_data(path: &str, folder: &str, day: Day) -> Result<String, InputError> {
+    utf8(path, load_data_bytes(path, folder, day)?)
+}
+
+fn utf8(path: &str, contents: Vec<u8>) -> Result<String, InputError> {
+    String::from_utf8(contents).map_err(|_| InputError::NotUtf8(path.into()))
+}
+
+/// Like [`load_data`], skipping the UTF-8 validation. The contents embedded at compile time
+/// take precedence over the filesystem.
+///
+/// # Errors
+///
+/// Will return [`InputError`] if the file could not be read, see [`load_bytes`].
+pub fn load_data_bytes(path: &str, folder: &str, day: Day) -> Result<Vec<u8>, InputError> {
+    #[cfg(feature = "embed")]
+    if let Some(contents) = crate::template::embedded::get(path) {
+        return checked(path, folder, day, contents.as_bytes().to_vec());
+    }
+    load_bytes(path, folder, day)
+}
+
+/// Reads the data file of `day` at `path` as bytes, skipping the UTF-8 validation of [`load`].
+///
+/// Prints warnings about sloppy whitespace if `inputs.lint` is enabled.
+///
+/// # Errors
+///
+/// Will return [`InputError`] if the file is missing, an input is empty, or it could not be read.
+pub fn load_bytes(path: &str, folder: &str, day: Day) -> Result<Vec<u8>, InputError> {
+    let contents = vault::read(path).map_err(|e| match e {
+        VaultError::Io(_, e) if e.kind() == io::ErrorKind::NotFound => InputError::Missing {
+            path: path.into(),
+            folder: folder.into(),
+            day,
+        },
+        e => InputError::Unreadable(e),
+    })?;
+    checked(path, folder, day, contents)
+}
+
+/// Rejects empty inputs, normalises `contents` and lints them.
+fn checked(path: &str, folder: &str, day: Day, contents: Vec<u8>) -> Result<Vec<u8>, InputError> {
+    if folder == "inputs" && contents.iter().all(u8::is_ascii_whitespace) {
+        return Err(InputError::Empty {
+            path: path.into(),
+            day,
+        });
+    }
+
+    let contents = normalize(contents);
+    if config::get().inputs.lint {
+        for warning in lint(&contents) {
+            eprintln!("Warning: \"{path}\" {warning}.");
+        }
+    }
+    Ok(contents)
+}
+
+/// Converts CRLF line endings to LF and removes byte order marks.
+#[must_use]
+pub fn normalize(contents: Vec<u8>) -> Vec<u8> {
+    if !contents.iter().any(|&b| b == b'\r' || b == BOM[0]) {
+        return contents;
+    }
+
+    let mut normalized = Vec::with_capacity(contents.len());
+    let mut rest = contents.as_slice();
+    while let [byte, tail @ ..] = rest {
+        if let Some(tail) = rest.strip_prefix(BOM) {
+            rest = tail;
+        } else if let Some(tail) = rest.strip_prefix(b"\r\n") {
+            normalized.push(b'\n');
+            rest = tail;
+        } else {
+            normalized.push(*byte);
+            rest = tail;
+        }
+    }
+    normalized
+}
+
+/// Finds whitespace that solutions tend to trip over, e.g. when splitting on `"\n\n"`.
+fn lint(contents: &[u8]) -> Vec<String> {
+    let mut warnings = vec![];
+
+    let trailing: Vec<usize> = contents
+        .split(|&b| b == b'\n')
+        .enumerate()
+        .filter(|(_, line)| line.ends_with(b" ") || line.ends_with(b"\t"))
+        .map(|(i, _)| i + 1)
+        .collect();
+    match trailing.as_slice() {
+        [] => {}
+        [line] => warnings.push(format!("has trailing whitespace on line {line}")),
+        [first, rest @ ..] => warnings.push(format!(
+            "has trailing whitespace on line {first} and {} more",
+            rest.len()
+        )),
+    }
+
+    if !contents.is_empty() && !contents.ends_with(b"\n") {
+        warnings.push("has no trailing newline".into());
+    }
+    warnings
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{InputError, lint, normalize};
+    use crate::day;
+
+    #[test]
+    fn normalizes_line_endings_and_boms() {
+        let normalize =
+            |contents: &str| String::from_utf8(normalize(contents.as_bytes().to_vec())).unwrap();
+        assert_eq!(normalize("\u{feff}1 2\r\n\r\n3 4\r\n"), "1 2\n\n3 4\n");
+        assert_eq!(normalize("a\rb\n\u{feff}"), "a\rb\n");
+        assert_eq!(normalize("1 2\n"), "1 2\n");
+    }
+
+    #[test]
+    fn lints_whitespace() {
+        let lint = |contents: &str| lint(contents.as_bytes());
+        assert!(lint("1 2\n3 4\n").is_empty());
+        assert!(lint("").is_empty());
+        assert_eq!(
+            lint("1 2 \n3 4"),
+            [
+                "has trailing whitespace on line 1",
+                "has no trailing newline"
+            ]
+        );
+        assert_eq!(
+            lint("a\t\nb \nc \n"),
+            ["has trailing whitespace on line 1 and 2 more"]
+        );
+    }
+
+    #[test]
+    fn errors_suggest_a_fix() {
+        let missing = |folder: &str| InputError::Missing {
+            path: format!("data/2025/{folder}/05.txt"),
+            folder: folder.into(),
+            day: day!(5),
+        };
+
+        assert_eq!(
+            missing("inputs").to_string(),
+            "\"data/2025/inputs/05.txt\" does not exist. Run `cargo download 05` first."
+        );
+        assert_eq!(
+            missing("examples").to_string(),
+            "\"data/2025/examples/05.txt\" does not exist. Run `cargo extract-example 05` or paste the example into it."
+        );
+        assert_eq!(
+            InputError::Empty {
+                path: "data/2025/inputs/05.txt".into(),
+                day: day!(5)
+            }
+            .to_string(),
+            "\"data/2025/inputs/05.txt\" is empty. Run `cargo download 05` to fetch the input again."
+        );
+    }
+}
@@ -0,0 +1,332 @@
+//! A small terminal renderer for the markdown of puzzle descriptions.
+//!
+//! Only the subset produced for puzzle pages is supported: headings, paragraphs,
+//! lists, code blocks, emphasis, code spans and links.
+
+use std::{
+    env,
+    process::{Command, Stdio},
+};
+
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+const ANSI_DIM: &str = "\x1b[2m";
+
+/// The widest column paragraphs are wrapped to, even on wider terminals.
+pub const MAX_WIDTH: usize = 100;
+
+/// Returns the terminal size in columns and lines, from `COLUMNS` and `LINES` or `tput`.
+/// Without a terminal, `tput` fails quietly and the defaults are used.
+pub fn terminal_size() -> (usize, usize) {
+    let size = |var: &str, cap: &str, default: usize| {
+        env::var(var)
+            .ok()
+            .or_else(|| {
+                // stdout is captured, `tput` finds the terminal through stdin instead.
+                let output = Command::new("tput")
+                    .arg(cap)
+                    .stdin(Stdio::inherit())
+                    .stderr(Stdio::null())
+                    .output()
+                    .ok()?;
+                String::from_utf8(output.stdout).ok()
+            })
+            .and_then(|x| x.trim().parse().ok())
+            .filter(|x| *x > 0)
+            .unwrap_or(default)
+    };
+    (size("COLUMNS", "cols", 80), size("LINES", "lines", 24))
+}
+
+/// Renders `markdown` for a terminal, wrapping paragraphs to `width` columns.
+/// Styles are only emitted if `ansi` is set.
+#[must_use]
+pub fn render(markdown: &str, width: usize, ansi: bool) -> String {
+    let mut blocks = Blocks {
+        out: vec![],
+        paragraph: vec![],
+        list: vec![],
+        width,
+        ansi,
+    };
+    let mut code_block: Option<Vec<&str>> = None;
+    let lines: Vec<&str> = markdown.lines().collect();
+
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        i += 1;
+
+        if line.trim_start().starts_with("```") {
+            if let Some(block) = code_block.take() {
+                blocks.out.push(frame(&block, width, ansi));
+            } else {
+                blocks.flush();
+                code_block = Some(vec![]);
+            }
+            continue;
+        }
+        if let Some(block) = code_block.as_mut() {
+            block.push(line);
+            continue;
+        }
+
+        let underlined = lines.get(i).is_some_and(|next| is_underline(next));
+        if line.starts_with('#') || underlined || is_aoc_heading(line) {
+            blocks.flush();
+            let text = strip_ansi(&inline(line.trim_start_matches('#').trim(), false));
+            blocks.out.push(if ansi {
+                format!("{ANSI_BOLD}{text}{ANSI_RESET}")
+            } else {
+                text
+            });
+            if underlined {
+                i += 1;
+            }
+        } else if line.trim().is_empty() {
+            blocks.flush();
+        } else {
+            if is_list_item(line) {
+                blocks.flush_paragraph();
+            }
+            blocks.paragraph.push(line.trim());
+        }
+    }
+
+    if let Some(block) = code_block {
+        blocks.out.push(frame(&block, width, ansi));
+    }
+    blocks.flush();
+
+    blocks.out.join("\n\n") + "\n"
+}
+
+/// Collects rendered blocks, which are separated by blank lines.
+struct Blocks<'a> {
+    out: Vec<String>,
+    paragraph: Vec<&'a str>,
+    /// Items of the current list, which are kept together without blank lines.
+    list: Vec<String>,
+    width: usize,
+    ansi: bool,
+}
+
+impl Blocks<'_> {
+    fn flush(&mut self) {
+        self.flush_paragraph();
+        if !self.list.is_empty() {
+            self.out.push(self.list.join("\n"));
+            self.list.clear();
+        }
+    }
+
+    /// Wraps the collected lines of a paragraph or list item.
+    fn flush_paragraph(&mut self) {
+        if self.paragraph.is_empty() {
+            return;
+        }
+
+        let text = self.paragraph.join(" ");
+        self.paragraph.clear();
+
+        let (bullet, text) = match text.split_once(' ') {
+            Some((marker, rest)) if is_list_item(&text) => {
+                let bullet = if marker == "*" || marker == "-" {
+                    "•"
+                } else {
+                    marker
+                };
+                (format!("{bullet} "), rest)
+            }
+            _ => (String::new(), text.as_str()),
+        };
+
+        let indent = " ".repeat(bullet.chars().count());
+        let lines: Vec<String> = wrap(
+            &inline(text, self.ansi),
+            self.width.saturating_sub(indent.len()),
+        )
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| format!("{}{line}", if i == 0 { &bullet } else { &indent }))
+        .collect();
+
+        if bullet.is_empty() {
+            self.flush();
+            self.out.push(lines.join("\n"));
+        } else {
+            self.list.push(lines.join("\n"));
+        }
+    }
+}
+
+fn is_list_item(line: &str) -> bool {
+    let line = line.trim_start();
+    line.starts_with("* ")
+        || line.starts_with("- ")
+        || line
+            .split_once(". ")
+            .is_some_and(|(n, _)| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn is_underline(line: &str) -> bool {
+    let line = line.trim();
+    line.len() >= 3 && (line.chars().all(|c| c == '-') || line.chars().all(|c| c == '='))
+}
+
+/// Puzzle headings look like `--- Day 1: Title ---` or `--- Part Two ---`.
+fn is_aoc_heading(line: &str) -> bool {
+    let line = line.replace('\\', "");
+    let line = line.trim();
+    line.starts_with("--- ") && line.ends_with(" ---")
+}
+
+/// Draws a frame around a code block. Code is never wrapped.
+fn frame(block: &[&str], width: usize, ansi: bool) -> String {
+    let inner = block
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0)
+        .clamp(1, width.saturating_sub(2).max(1));
+    let (dim, reset) = if ansi {
+        (ANSI_DIM, ANSI_RESET)
+    } else {
+        ("", "")
+    };
+
+    let mut lines = vec![format!("{dim}┌{}{reset}", "─".repeat(inner + 1))];
+    lines.extend(
+        block
+            .iter()
+            .map(|line| format!("{dim}│{reset} {}", strip_emphasis(line))),
+    );
+    lines.push(format!("{dim}└{}{reset}", "─".repeat(inner + 1)));
+    lines.join("\n")
+}
+
+/// Emphasis inside code blocks is marked with `<em>` tags, which are not rendered.
+fn strip_emphasis(line: &str) -> String {
+    line.replace("<em>", "").replace("</em>", "")
+}
+
+/// Resolves escapes, links, code spans and emphasis of a line of text.
+fn inline(text: &str, ansi: bool) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut bold = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek().is_some_and(char::is_ascii_punctuation) => {
+                out.extend(chars.next());
+            }
+            '*' | '_' if c == '*' || chars.peek() == Some(&'_') => {
+                // `**` and `*` both toggle emphasis, puzzle text does not nest them.
+                if chars.peek() == Some(&c) {
+                    chars.next();
+                }
+                bold = !bold;
+                if ansi {
+                    out.push_str(if bold { ANSI_BOLD } else { ANSI_RESET });
+                }
+            }
+            '`' => {}
+            '[' => {
+                // links are rendered as their text only.
+                let rest: String = chars.clone().collect();
+                if let Some((label, after)) = rest.split_once("](")
+                    && !label.contains(']')
+                    && let Some(end) = after.find(')')
+                {
+                    out.push_str(&inline(label, ansi));
+                    let skip = label.chars().count() + 2 + after[..=end].chars().count();
+                    for _ in 0..skip {
+                        chars.next();
+                    }
+                } else {
+                    out.push(c);
+                }
+            }
+            c => out.push(c),
+        }
+    }
+
+    if bold && ansi {
+        out.push_str(ANSI_RESET);
+    }
+    out
+}
+
+/// Greedily wraps text at spaces, ignoring ANSI escapes when measuring.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = String::new();
+    let mut line_width = 0;
+
+    for word in text.split_whitespace() {
+        let word_width = strip_ansi(word).chars().count();
+        if line_width > 0 && line_width + 1 + word_width > width {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0;
+        }
+        if line_width > 0 {
+            line.push(' ');
+            line_width += 1;
+        }
+        line.push_str(word);
+        line_width += word_width;
+    }
+
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|c| *c == 'm');
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{inline, render, wrap};
+
+    #[test]
+    fn renders_plain_text() {
+        let markdown = "\\--- Day 1: Title ---\n----------\n\nSome *emphasised* text\nwith a [link](https://example.com).\n\n* one\n* two\n\n```\n3   4\n```\n";
+        assert_eq!(
+            render(markdown, 80, false),
+            "--- Day 1: Title ---\n\nSome emphasised text with a link.\n\n• one\n• two\n\n┌──────\n│ 3   4\n└──────\n"
+        );
+    }
+
+    #[test]
+    fn styles_emphasis() {
+        assert_eq!(
+            inline("a total of `*11*`", true),
+            "a total of \x1b[1m11\x1b[0m"
+        );
+        assert_eq!(inline("2 \\* 3", true), "2 * 3");
+    }
+
+    #[test]
+    fn wraps_without_counting_styles() {
+        assert_eq!(
+            wrap("aaa \x1b[1mbbb\x1b[0m ccc", 7),
+            vec!["aaa \x1b[1mbbb\x1b[0m", "ccc"]
+        );
+        assert_eq!(wrap("", 10), vec![""]);
+    }
+}
@@ -1,65 +1,195 @@
-use std::{env, fs};
-
+pub mod answer_cache;
+pub mod answers;
 pub mod aoc_cli;
+pub mod bytes;
+#[cfg(feature = "today")]
+pub mod clock;
 pub mod commands;
+pub mod config;
+#[cfg(feature = "embed")]
+pub mod embedded;
+pub mod input;
+pub mod paths;
+pub mod puzzle;
+pub mod render;
 pub mod runner;
+pub mod selection;
+pub mod submission;
+pub mod submission_log;
+pub mod vault;
 
 pub use day::*;
+pub use input::InputError;
+pub use year::*;
 
+mod dashboard;
 mod day;
+mod markdown;
 mod readme_benchmarks;
 mod run_multi;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Reads a data file to a string with `\n` line endings, decrypting its locked copy if needed.
+///
+/// # Errors
+///
+/// Will return [`InputError`] naming the path if the file is missing, an input is empty,
+/// or the file could not be read.
+pub fn try_read_file(folder: &str, year: Year, day: Day) -> Result<String, InputError> {
+    input::load_data(&paths::data_path(folder, year, day), folder, day)
+}
+
+/// Like [`try_read_file`], appending a part suffix. E.g. like `01-2.txt`.
+///
+/// # Errors
+///
+/// Will return [`InputError`] if the file could not be read, see [`try_read_file`].
+pub fn try_read_file_part(
+    folder: &str,
+    year: Year,
+    day: Day,
+    part: u8,
+) -> Result<String, InputError> {
+    input::load_data(&paths::data_part_path(folder, year, day, part), folder, day)
+}
+
+/// Helper function that reads a text file to a string, see [`try_read_file`].
 ///
 /// # Panics
 ///
-/// Will panic if the user has insufficient permissions to access the current directory.
+/// Will panic with the error and a hint if the file could not be read.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
+    try_read_file(folder, year, day).unwrap_or_else(|e| panic!("{e}"))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 ///
 /// # Panics
 ///
-/// Will panic if the user has insufficient permissions to access the current directory.
+/// Will panic with the error and a hint if the file could not be read.
+#[must_use]
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
+    try_read_file_part(folder, year, day, part).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Reads the input of a solution: the file passed with `--input`, or the input of `day`.
+///
+/// # Errors
+///
+/// Will return [`InputError`] if the file could not be read, see [`try_read_file`].
+pub fn try_read_input(year: Year, day: Day) -> Result<String, InputError> {
+    runner::input_arg().map_or_else(
+        || try_read_file("inputs", year, day),
+        |path| input::load(&path, &parent_folder(&path), day),
+    )
+}
+
+/// Like [`try_read_input`], reading bytes as [`try_read_bytes`] does.
+///
+/// # Errors
+///
+/// Will return [`InputError`] if the file could not be read, see [`try_read_file`].
+pub fn try_read_input_bytes(year: Year, day: Day) -> Result<Vec<u8>, InputError> {
+    runner::input_arg().map_or_else(
+        || try_read_bytes("inputs", year, day),
+        |path| input::load_bytes(&path, &parent_folder(&path), day),
+    )
+}
+
+/// The data folder of a file passed with `--input`, e.g. `examples` for `data/2025/examples/01.txt`.
+fn parent_folder(path: &str) -> String {
+    std::path::Path::new(path)
+        .parent()
+        .and_then(|parent| parent.file_name())
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Reads a data file to bytes with `\n` line endings, skipping the UTF-8 validation of [`try_read_file`].
+///
+/// # Errors
+///
+/// Will return [`InputError`] if the file could not be read, see [`try_read_file`].
+pub fn try_read_bytes(folder: &str, year: Year, day: Day) -> Result<Vec<u8>, InputError> {
+    input::load_data_bytes(&paths::data_path(folder, year, day), folder, day)
+}
+
+/// Like [`try_read_bytes`], appending a part suffix. E.g. like `01-2.txt`.
+///
+/// # Errors
+///
+/// Will return [`InputError`] if the file could not be read, see [`try_read_file`].
+pub fn try_read_bytes_part(
+    folder: &str,
+    year: Year,
+    day: Day,
+    part: u8,
+) -> Result<Vec<u8>, InputError> {
+    input::load_data_bytes(&paths::data_part_path(folder, year, day, part), folder, day)
+}
+
+/// Helper function that reads a data file to bytes, see [`try_read_bytes`].
+///
+/// # Panics
+///
+/// Will panic with the error and a hint if the file could not be read.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_bytes(folder: &str, year: Year, day: Day) -> Vec<u8> {
+    try_read_bytes(folder, year, day).unwrap_or_else(|e| panic!("{e}"))
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Helper function that reads a data file to bytes, appending a part suffix. E.g. like `01-2.txt`.
 ///
+/// # Panics
+///
+/// Will panic with the error and a hint if the file could not be read.
+#[must_use]
+pub fn read_bytes_part(folder: &str, year: Year, day: Day, part: u8) -> Vec<u8> {
+    try_read_bytes_part(folder, year, day, part).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
+///
+/// The year is taken from the name of the solution bin, e.g. `2025-01`.
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// On the final day of a calendar, only part one is run since there is no puzzle for part two.
+///
+/// The parts take the input as `&str`, unless `bytes` is passed as the last parameter:
+/// `solution!(4, bytes)` passes it as `&[u8]`, see [`template::bytes`](crate::template::bytes).
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl $day, try_read_input, [part_one, 1] [part_two, 2]);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl $day, try_read_input, [part_one, 1]);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl $day, try_read_input, [part_two, 2]);
+    };
+    ($day:expr, bytes) => {
+        $crate::solution!(@impl $day, try_read_input_bytes, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, 1, bytes) => {
+        $crate::solution!(@impl $day, try_read_input_bytes, [part_one, 1]);
     };
+    ($day:expr, 2, bytes) => {
+        $crate::solution!(@impl $day, try_read_input_bytes, [part_two, 2]);
+    };
+
+    (@impl $day:expr, $read:ident, $( [$func:expr, $part:expr] )*) => {
+        /// The current year.
+        const YEAR: $crate::template::Year = const {
+            $crate::template::Year::from_bin_name(env!("CARGO_BIN_NAME"))
+                .expect("invalid bin name, expecting a name like `2025-01`")
+        };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
@@ -69,8 +199,20 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            #[cfg(feature = "embed")]
+            $crate::template::embedded::register(const {
+                $crate::template::embedded::files(YEAR, DAY)
+            });
+            let input = $crate::template::$read(YEAR, DAY).unwrap_or_else(|e| {
+                eprintln!("{e}");
+                std::process::exit(1);
+            });
+            let mut renderer = $crate::template::render::OutputFormat::from_args().renderer();
+            $(
+                let numeric = (&Answer::of(&$func)).is_numeric();
+                run_part($func, &input[..], YEAR, DAY, $part, numeric, renderer.as_mut());
+            )*
+            renderer.finish(None);
         }
     };
 }
@@ -0,0 +1,84 @@
+//! Year-scoped locations of solution bins and data files.
+//!
+//! Solutions live in `src/bin/<year>-<day>.rs`, data files in `data/<year>/`.
+
+use crate::template::{Day, Year, config};
+
+/// Name of the solution bin for a day, e.g. `2025-01`.
+#[must_use]
+pub fn bin_name(year: Year, day: Day) -> String {
+    format!("{year}-{day}")
+}
+
+#[must_use]
+pub fn bin_path(year: Year, day: Day) -> String {
+    format!("./src/bin/{}.rs", bin_name(year, day))
+}
+
+/// The data directory of a year, e.g. `data/2025`. The parent directory is configured with `data_dir`.
+#[must_use]
+pub fn data_dir(year: Year) -> String {
+    format!("{}/{year}", config::get().data_dir)
+}
+
+/// Path of a day's file in one of the data folders, e.g. `data/2025/inputs/01.txt`.
+#[must_use]
+pub fn data_path(folder: &str, year: Year, day: Day) -> String {
+    format!("{}/{folder}/{day}.txt", data_dir(year))
+}
+
+/// Path of a day's file that only applies to one part, e.g. `data/2025/examples/01-2.txt`.
+#[must_use]
+pub fn data_part_path(folder: &str, year: Year, day: Day, part: u8) -> String {
+    format!("{}/{folder}/{day}-{part}.txt", data_dir(year))
+}
+
+#[must_use]
+pub fn input_path(year: Year, day: Day) -> String {
+    data_path("inputs", year, day)
+}
+
+#[must_use]
+pub fn example_path(year: Year, day: Day) -> String {
+    data_path("examples", year, day)
+}
+
+/// Path of an example that only applies to one part, e.g. `data/2025/examples/01-2.txt`.
+#[must_use]
+pub fn example_part_path(year: Year, day: Day, part: u8) -> String {
+    data_part_path("examples", year, day, part)
+}
+
+#[must_use]
+pub fn puzzle_path(year: Year, day: Day) -> String {
+    format!("{}/puzzles/{day}.md", data_dir(year))
+}
+
+/// Accepted answers of a year, harvested from the puzzle descriptions.
+#[must_use]
+pub fn answers_path(year: Year) -> String {
+    format!("{}/answers.json", data_dir(year))
+}
+
+/// The latest computed answers of a year, used by `cargo submit`.
+#[must_use]
+pub fn answer_cache_path(year: Year) -> String {
+    format!("{}/latest_answers.json", data_dir(year))
+}
+
+/// Every answer submitted for a year along with its verdict.
+#[must_use]
+pub fn submissions_path(year: Year) -> String {
+    format!("{}/submissions.json", data_dir(year))
+}
+
+/// Known answers of the examples of a year, e.g. `data/2025/examples/answers.json`.
+#[must_use]
+pub fn example_answers_path(year: Year) -> String {
+    format!("{}/examples/answers.json", data_dir(year))
+}
+
+#[must_use]
+pub fn timings_path(year: Year) -> String {
+    format!("./{}/timings.json", data_dir(year))
+}
@@ -0,0 +1,268 @@
+//! Helpers for puzzle descriptions downloaded to `data/<year>/puzzles/<day>.md`.
+
+/// Returns the title of a puzzle from its markdown description,
+/// e.g. `Historian Hysteria` for a heading like `--- Day 1: Historian Hysteria ---`.
+#[must_use]
+pub fn title(markdown: &str) -> Option<String> {
+    markdown.lines().find_map(|line| {
+        // headings may be escaped and prefixed, e.g. `## \-\-\- Day 1: ... \-\-\-`.
+        let line = line.replace('\\', "");
+        let rest = line
+            .trim_start_matches('#')
+            .trim()
+            .strip_prefix("--- Day ")?;
+        let (_, title) = rest.split_once(": ")?;
+        Some(title.trim_end_matches('-').trim().to_string())
+    })
+}
+
+/// The example of one part of a puzzle.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Example {
+    /// The most likely example input, if the part has a code block.
+    pub input: Option<String>,
+    /// The emphasised expected answer of the example.
+    pub answer: Option<String>,
+}
+
+/// Extracts the examples of both parts from a puzzle's markdown description.
+///
+/// The example input is the code block that is introduced as an example, preferring longer
+/// blocks. The answer is the last emphasised code span of a part, which is where the puzzle
+/// text states the result for the example.
+#[must_use]
+pub fn examples(markdown: &str) -> [Example; 2] {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let part_two = lines
+        .iter()
+        .position(|line| is_part_two_heading(line))
+        .unwrap_or(lines.len());
+
+    [&lines[..part_two], &lines[part_two..]].map(|section| {
+        // the description of a solved part ends with the submitted answer, which is not an example.
+        let end = section
+            .iter()
+            .position(|line| line.contains("Your puzzle answer was"))
+            .unwrap_or(section.len());
+        let section = &section[..end];
+
+        Example {
+            input: example_block(section),
+            answer: prose(section).flat_map(emphasised_code).last(),
+        }
+    })
+}
+
+/// Returns the answers that were accepted for a puzzle, in order of the parts.
+/// Solved parts end with a line like ``Your puzzle answer was `1234`.``
+#[must_use]
+pub fn submitted_answers(markdown: &str) -> Vec<String> {
+    markdown
+        .lines()
+        .filter_map(|line| {
+            let rest = line.split_once("Your puzzle answer was")?.1;
+            let (_, rest) = rest.split_once('`')?;
+            let (answer, _) = rest.split_once('`')?;
+            Some(unescape(answer.trim_matches('*')))
+        })
+        .collect()
+}
+
+/// Returns `true` if the description already includes part two,
+/// i.e. it was downloaded after part one was solved.
+#[must_use]
+pub fn has_part_two(markdown: &str) -> bool {
+    markdown.lines().any(is_part_two_heading)
+}
+
+/// Returns the description of part two, starting at its heading, if it is included.
+#[must_use]
+pub fn part_two(markdown: &str) -> Option<String> {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let start = lines.iter().position(|line| is_part_two_heading(line))?;
+    Some(lines[start..].join("\n"))
+}
+
+fn is_part_two_heading(line: &str) -> bool {
+    line.replace('\\', "").contains("--- Part Two ---")
+}
+
+/// Returns the lines of a section that are not part of a code block.
+fn prose<'a>(section: &'a [&'a str]) -> impl Iterator<Item = &'a str> {
+    let mut in_block = false;
+    section.iter().copied().filter(move |line| {
+        if line.trim_start().starts_with("```") {
+            in_block = !in_block;
+            return false;
+        }
+        !in_block
+    })
+}
+
+/// Picks the code block that is most likely the example input of a section.
+fn example_block(section: &[&str]) -> Option<String> {
+    let mut best: Option<(usize, String)> = None;
+    let mut block: Option<Vec<&str>> = None;
+    let mut paragraph = String::new();
+    let mut introduced = false;
+
+    for line in section {
+        if line.trim_start().starts_with("```") {
+            match block.take() {
+                None => {
+                    introduced = paragraph.to_lowercase().contains("example");
+                    block = Some(vec![]);
+                }
+                Some(lines) => {
+                    let score = lines.len() + if introduced { 1000 } else { 0 };
+                    if best.as_ref().is_none_or(|(best, _)| score > *best) {
+                        best = Some((score, unescape(&lines.join("\n"))));
+                    }
+                    paragraph.clear();
+                }
+            }
+        } else if let Some(lines) = block.as_mut() {
+            lines.push(line);
+        } else if line.trim().is_empty() {
+            // keep the last non-empty paragraph, it introduces the next code block.
+            if !paragraph.trim().is_empty() {
+                paragraph.push('\n');
+            }
+        } else {
+            if paragraph.ends_with('\n') {
+                paragraph.clear();
+            }
+            paragraph.push_str(line);
+            paragraph.push(' ');
+        }
+    }
+
+    best.map(|(_, input)| input + "\n")
+}
+
+/// Returns the values of emphasised code spans in a line, e.g. `143` for `` `*143*` `` or `*`143`*`.
+fn emphasised_code(line: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut rest = line;
+
+    while let Some(start) = rest.find("`*").into_iter().chain(rest.find("*`")).min() {
+        let (open, close) = if rest[start..].starts_with("`*") {
+            ("`*", "*`")
+        } else {
+            ("*`", "`*")
+        };
+        let inner = &rest[start + open.len()..];
+        let Some(end) = inner.find(close) else {
+            break;
+        };
+        if end > 0 {
+            values.push(unescape(&inner[..end]));
+        }
+        rest = &inner[end + close.len()..];
+    }
+
+    values
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        Example, emphasised_code, examples, has_part_two, part_two, submitted_answers, title,
+    };
+
+    const PUZZLE: &str = "\\--- Day 1: Historian Hysteria ---
+----------
+
+Some text, but there is a list:
+
+```
+not the example
+```
+
+For example:
+
+```
+3   4
+4   3
+```
+
+In the example above, this is `2 + 1`, a total distance of `*11*`!
+
+Your puzzle answer was `1234`.
+
+\\--- Part Two ---
+----------
+
+Using the same example, the similarity score is *`31`*.
+";
+
+    #[test]
+    fn parses_titles() {
+        assert_eq!(
+            title("\\--- Day 1: Historian Hysteria ---\n----------\n\nThe text."),
+            Some("Historian Hysteria".into())
+        );
+        assert_eq!(
+            title("## \\-\\-\\- Day 12: Garden Groups \\-\\-\\-\n"),
+            Some("Garden Groups".into())
+        );
+        assert_eq!(title("no heading here"), None);
+    }
+
+    #[test]
+    fn extracts_examples() {
+        let [one, two] = examples(PUZZLE);
+        assert_eq!(
+            one,
+            Example {
+                input: Some("3   4\n4   3\n".into()),
+                answer: Some("11".into()),
+            }
+        );
+        assert_eq!(
+            two,
+            Example {
+                input: None,
+                answer: Some("31".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn finds_submitted_answers() {
+        assert_eq!(submitted_answers(PUZZLE), vec!["1234"]);
+        assert_eq!(
+            submitted_answers("Your puzzle answer was `1`.\n\nYour puzzle answer was `ab,cd`."),
+            vec!["1", "ab,cd"]
+        );
+    }
+
+    #[test]
+    fn detects_part_two() {
+        assert!(part_two(PUZZLE).unwrap().starts_with("\\--- Part Two ---"));
+        assert!(has_part_two(PUZZLE));
+        assert!(!has_part_two("\\--- Day 1: Historian Hysteria ---\n"));
+    }
+
+    #[test]
+    fn finds_emphasised_code() {
+        assert_eq!(
+            emphasised_code("is `*143*` and *`a&lt;b`*"),
+            vec!["143", "a<b"]
+        );
+        assert_eq!(
+            emphasised_code("plain `code` and *emphasis*"),
+            Vec::<String>::new()
+        );
+    }
+}
@@ -0,0 +1,637 @@
+//! Renders structured solution results in one of several output formats.
+//!
+//! Day binaries and the multi-day commands share the same [`PartResult`] records:
+//! when `all` or `time` invoke a day binary, they ask it for `--format json` and feed the
+//! parsed records into their own renderer.
+
+use std::{
+    collections::HashMap,
+    env,
+    error::Error,
+    fmt::Display,
+    io::{IsTerminal, Write, stdout},
+    str::FromStr,
+    time::Duration,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, dashboard::DashboardRenderer};
+
+/// The output format used to render results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Plain,
+    Ansi,
+    /// A stream of JSON objects, one per line, each with a `type` of `answer`, `bench`,
+    /// `progress`, `part` or `total`.
+    Json,
+    Markdown,
+    /// A full-screen grid of days and parts that is redrawn as results arrive.
+    Dashboard,
+}
+
+impl OutputFormat {
+    /// Picks [`OutputFormat::Ansi`] when stdout is a terminal and `NO_COLOR` is not set,
+    /// [`OutputFormat::Plain`] otherwise.
+    #[must_use]
+    pub fn detect() -> Self {
+        let no_color = env::var_os("NO_COLOR").is_some_and(|x| !x.is_empty());
+        if no_color || !stdout().is_terminal() {
+            Self::Plain
+        } else {
+            Self::Ansi
+        }
+    }
+
+    /// Reads the `--format` argument passed to a day binary, falling back to [`OutputFormat::detect`].
+    #[must_use]
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        args.iter()
+            .position(|x| x == "--format")
+            .and_then(|i| args.get(i + 1))
+            .and_then(|x| x.parse().ok())
+            .unwrap_or_else(Self::detect)
+    }
+
+    #[must_use]
+    pub fn renderer(self) -> Box<dyn Renderer> {
+        match self {
+            Self::Plain => Box::new(PlainRenderer { ansi: false }),
+            Self::Ansi => Box::new(PlainRenderer { ansi: true }),
+            Self::Json => Box::new(JsonRenderer),
+            Self::Markdown => Box::new(MarkdownRenderer::default()),
+            Self::Dashboard => Box::new(DashboardRenderer::default()),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Plain => "plain",
+            Self::Ansi => "ansi",
+            Self::Json => "json",
+            Self::Markdown => "markdown",
+            Self::Dashboard => "dashboard",
+        })
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Self::Plain),
+            "ansi" => Ok(Self::Ansi),
+            "json" => Ok(Self::Json),
+            "markdown" | "md" => Ok(Self::Markdown),
+            "dashboard" => Ok(Self::Dashboard),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
+
+impl Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `plain`, `ansi`, `json`, `markdown` or `dashboard`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The result of running one part of a solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartResult {
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Average duration of a single run.
+    pub duration: Duration,
+    pub samples: u128,
+}
+
+impl PartResult {
+    /// Formats the duration like `74.1µs`, the representation stored in the timings file.
+    #[must_use]
+    pub fn duration_str(&self) -> String {
+        format!("{:.1?}", self.duration)
+    }
+
+    fn timing_str(&self) -> String {
+        if self.samples == 1 {
+            format!("({})", self.duration_str())
+        } else {
+            format!("({} @ {} samples)", self.duration_str(), self.samples)
+        }
+    }
+}
+
+/// Receives results as a run progresses and writes them to stdout.
+pub trait Renderer {
+    /// Called once before a multi-day run with every day that is about to run.
+    fn start(&mut self, _days: &[Day]) {}
+
+    fn day_started(&mut self, _day: Day) {}
+
+    /// Called with the answer of the first, untimed run of a part.
+    fn part_answered(&mut self, _result: &PartResult) {}
+
+    /// Called before a part is benched for the given number of iterations.
+    fn bench_started(&mut self, _day: Day, _part: u8, _iterations: u128) {}
+
+    /// Called periodically while a part is benched.
+    fn bench_progress(&mut self, _day: Day, _part: u8, _done: u128, _iterations: u128) {}
+
+    /// Called with the final result of a part, including bench timings if any.
+    fn part_finished(&mut self, result: &PartResult);
+
+    /// Called when a day has been run. `solved` is false if the day has no solution yet.
+    fn day_finished(&mut self, _day: Day, _solved: bool) {}
+
+    /// Called instead of [`Renderer::day_finished`] if the solution of a day did not build
+    /// or exited with an error, e.g. because a part panicked.
+    fn day_failed(&mut self, day: Day, error: &str) {
+        self.diagnostic(&format!("Day {day}: {error}"));
+    }
+
+    /// Called once at the end of a run, with the total time if it was benched.
+    fn finish(&mut self, _total_millis: Option<f64>) {}
+
+    /// Forwards a line of unrelated output, e.g. debug prints from a solution.
+    fn passthrough(&mut self, line: &str) {
+        println!("{line}");
+    }
+
+    /// Forwards a line a solution wrote to stderr, e.g. a compiler error or a panic message.
+    fn diagnostic(&mut self, line: &str) {
+        eprintln!("{line}");
+    }
+}
+
+/// Discards all output of the solutions, for commands that only compare their answers.
+pub struct Silent;
+
+impl Renderer for Silent {
+    fn part_finished(&mut self, _result: &PartResult) {}
+
+    fn passthrough(&mut self, _line: &str) {}
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Human-readable output, optionally styled and overwritten in place with ANSI escapes.
+struct PlainRenderer {
+    ansi: bool,
+}
+
+impl PlainRenderer {
+    const fn style<'a>(&self, code: &'a str) -> &'a str {
+        if self.ansi { code } else { "" }
+    }
+}
+
+impl Renderer for PlainRenderer {
+    fn day_started(&mut self, day: Day) {
+        let (bold, reset) = (self.style(ANSI_BOLD), self.style(ANSI_RESET));
+        println!("{bold}Day {day}{reset}");
+        println!("------");
+    }
+
+    fn part_answered(&mut self, result: &PartResult) {
+        // intermediate results are only shown when they can be overwritten later.
+        if !self.ansi {
+            return;
+        }
+
+        match &result.answer {
+            Some(answer) if answer.contains('\n') => print!("Part {}: ▼", result.part),
+            Some(answer) => print!("Part {}: {ANSI_BOLD}{answer}{ANSI_RESET}", result.part),
+            None => print!("Part {}: ✖", result.part),
+        }
+        let _ = stdout().flush();
+    }
+
+    fn bench_started(&mut self, _day: Day, _part: u8, _iterations: u128) {
+        if self.ansi {
+            print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+            let _ = stdout().flush();
+        }
+    }
+
+    fn part_finished(&mut self, result: &PartResult) {
+        if self.ansi {
+            print!("\r");
+        }
+
+        let (bold, reset) = (self.style(ANSI_BOLD), self.style(ANSI_RESET));
+        let part = result.part;
+        let timing = result.timing_str();
+
+        match &result.answer {
+            Some(answer) if answer.contains('\n') => {
+                println!("Part {part}: ▼ {timing}");
+                println!("{answer}");
+            }
+            Some(answer) => println!("Part {part}: {bold}{answer}{reset} {timing}"),
+            None => println!("Part {part}: ✖             "),
+        }
+    }
+
+    fn day_finished(&mut self, _day: Day, solved: bool) {
+        if !solved {
+            println!("Not solved.");
+        }
+    }
+
+    fn finish(&mut self, total_millis: Option<f64>) {
+        if let Some(total_millis) = total_millis {
+            let (bold, italic, reset) = (
+                self.style(ANSI_BOLD),
+                self.style(ANSI_ITALIC),
+                self.style(ANSI_RESET),
+            );
+            println!("\n{bold}Total (Run):{reset} {italic}{total_millis:.2}ms{reset}");
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Newline-delimited JSON records. Unrelated output is moved to stderr to keep stdout parseable.
+struct JsonRenderer;
+
+impl Renderer for JsonRenderer {
+    fn part_answered(&mut self, result: &PartResult) {
+        println!("{}", Record::Answer(result.clone()).to_json());
+    }
+
+    fn bench_started(&mut self, day: Day, part: u8, iterations: u128) {
+        let record = Record::Bench {
+            day,
+            part,
+            iterations,
+        };
+        println!("{}", record.to_json());
+    }
+
+    fn bench_progress(&mut self, day: Day, part: u8, done: u128, iterations: u128) {
+        let record = Record::Progress {
+            day,
+            part,
+            done,
+            iterations,
+        };
+        println!("{}", record.to_json());
+    }
+
+    fn part_finished(&mut self, result: &PartResult) {
+        println!("{}", Record::Part(result.clone()).to_json());
+    }
+
+    fn day_failed(&mut self, day: Day, error: &str) {
+        let record = Record::Failed {
+            day,
+            error: error.into(),
+        };
+        println!("{}", record.to_json());
+    }
+
+    fn finish(&mut self, total_millis: Option<f64>) {
+        if let Some(total_millis) = total_millis {
+            println!("{}", Record::Total { total_millis }.to_json());
+        }
+    }
+
+    fn passthrough(&mut self, line: &str) {
+        eprintln!("{line}");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A row of the Markdown table.
+enum MarkdownRow {
+    Part(PartResult),
+    Failed(Day),
+}
+
+/// A Markdown table of all results, printed once the run has finished.
+#[derive(Default)]
+struct MarkdownRenderer {
+    rows: Vec<MarkdownRow>,
+}
+
+impl Renderer for MarkdownRenderer {
+    fn part_finished(&mut self, result: &PartResult) {
+        self.rows.push(MarkdownRow::Part(result.clone()));
+    }
+
+    fn day_failed(&mut self, day: Day, error: &str) {
+        self.rows.push(MarkdownRow::Failed(day));
+        self.diagnostic(&format!("Day {day}: {error}"));
+    }
+
+    fn finish(&mut self, total_millis: Option<f64>) {
+        println!("| Day | Part | Answer | Time |");
+        println!("| :---: | :---: | :---: | :---: |");
+
+        for row in &self.rows {
+            let result = match row {
+                MarkdownRow::Part(result) => result,
+                MarkdownRow::Failed(day) => {
+                    println!("| {day} | - | failed | - |");
+                    continue;
+                }
+            };
+            let answer = result
+                .answer
+                .as_ref()
+                .map_or_else(|| "-".into(), |x| format!("`{}`", x.replace('\n', "<br>")));
+            println!(
+                "| {} | {} | {answer} | `{}` |",
+                result.day,
+                result.part,
+                result.duration_str()
+            );
+        }
+
+        if let Some(total_millis) = total_millis {
+            println!();
+            println!("**Total: {total_millis:.2}ms**");
+        }
+    }
+
+    fn passthrough(&mut self, line: &str) {
+        eprintln!("{line}");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A single line of [`OutputFormat::Json`] output.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Record {
+    Answer(PartResult),
+    Bench {
+        day: Day,
+        part: u8,
+        iterations: u128,
+    },
+    Progress {
+        day: Day,
+        part: u8,
+        done: u128,
+        iterations: u128,
+    },
+    Part(PartResult),
+    Failed {
+        day: Day,
+        error: String,
+    },
+    Total {
+        total_millis: f64,
+    },
+}
+
+impl Record {
+    /// # Panics
+    ///
+    /// Will panic if the record contains a non-finite number, which tinyjson cannot serialize.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        JsonValue::from(self)
+            .stringify()
+            .expect("records only contain finite numbers")
+    }
+
+    /// Forwards the record to the matching [`Renderer`] callback.
+    pub fn render(&self, renderer: &mut dyn Renderer) {
+        match self {
+            Self::Answer(result) => renderer.part_answered(result),
+            Self::Bench {
+                day,
+                part,
+                iterations,
+            } => renderer.bench_started(*day, *part, *iterations),
+            Self::Progress {
+                day,
+                part,
+                done,
+                iterations,
+            } => renderer.bench_progress(*day, *part, *done, *iterations),
+            Self::Part(result) => renderer.part_finished(result),
+            Self::Failed { day, error } => renderer.day_failed(*day, error),
+            Self::Total { total_millis } => renderer.finish(Some(*total_millis)),
+        }
+    }
+}
+
+impl From<&Record> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Record) -> Self {
+        let mut map: HashMap<String, Self> = HashMap::new();
+
+        let (kind, result) = match value {
+            Record::Answer(result) => ("answer", Some(result)),
+            Record::Part(result) => ("part", Some(result)),
+            Record::Bench {
+                day,
+                part,
+                iterations,
+            } => {
+                map.insert("day".into(), Self::String(day.to_string()));
+                map.insert("part".into(), Self::Number(f64::from(*part)));
+                map.insert("iterations".into(), Self::Number(*iterations as f64));
+                ("bench", None)
+            }
+            Record::Progress {
+                day,
+                part,
+                done,
+                iterations,
+            } => {
+                map.insert("day".into(), Self::String(day.to_string()));
+                map.insert("part".into(), Self::Number(f64::from(*part)));
+                map.insert("done".into(), Self::Number(*done as f64));
+                map.insert("iterations".into(), Self::Number(*iterations as f64));
+                ("progress", None)
+            }
+            Record::Failed { day, error } => {
+                map.insert("day".into(), Self::String(day.to_string()));
+                map.insert("error".into(), Self::String(error.clone()));
+                ("failed", None)
+            }
+            Record::Total { total_millis } => {
+                map.insert("total_millis".into(), Self::Number(*total_millis));
+                ("total", None)
+            }
+        };
+
+        map.insert("type".into(), Self::String(kind.into()));
+
+        if let Some(result) = result {
+            map.insert("day".into(), Self::String(result.day.to_string()));
+            map.insert("part".into(), Self::Number(f64::from(result.part)));
+            map.insert(
+                "answer".into(),
+                result.answer.clone().map_or(Self::Null, Self::String),
+            );
+            map.insert(
+                "nanos".into(),
+                Self::Number(result.duration.as_nanos() as f64),
+            );
+            map.insert("samples".into(), Self::Number(result.samples as f64));
+        }
+
+        Self::Object(map)
+    }
+}
+
+impl FromStr for Record {
+    type Err = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON."))?;
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or_else(|| format!("Expected record.{key} to be a number."))
+        };
+
+        let day = || {
+            json.get("day")
+                .and_then(|v| v.get::<String>())
+                .and_then(|day| Day::from_str(day).ok())
+                .ok_or("Expected record.day to be a Day struct.")
+        };
+
+        let part_result = || -> Result<PartResult, String> {
+            let answer = json
+                .get("answer")
+                .map(|v| if v.is_null() { None } else { v.get::<String>() })
+                .ok_or("Expected record.answer to be null or string.")?;
+
+            Ok(PartResult {
+                day: day()?,
+                part: number("part")? as u8,
+                answer: answer.cloned(),
+                duration: Duration::from_nanos(number("nanos")? as u64),
+                samples: number("samples")? as u128,
+            })
+        };
+
+        match json.get("type").and_then(|v| v.get::<String>()) {
+            Some(kind) if kind == "answer" => Ok(Self::Answer(part_result()?)),
+            Some(kind) if kind == "part" => Ok(Self::Part(part_result()?)),
+            Some(kind) if kind == "bench" => Ok(Self::Bench {
+                day: day()?,
+                part: number("part")? as u8,
+                iterations: number("iterations")? as u128,
+            }),
+            Some(kind) if kind == "progress" => Ok(Self::Progress {
+                day: day()?,
+                part: number("part")? as u8,
+                done: number("done")? as u128,
+                iterations: number("iterations")? as u128,
+            }),
+            Some(kind) if kind == "failed" => Ok(Self::Failed {
+                day: day()?,
+                error: json
+                    .get("error")
+                    .and_then(|v| v.get::<String>())
+                    .cloned()
+                    .ok_or("Expected record.error to be a string.")?,
+            }),
+            Some(kind) if kind == "total" => Ok(Self::Total {
+                total_millis: number("total_millis")?,
+            }),
+            _ => Err("Expected record.type to be a known record type.".into()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{OutputFormat, PartResult, Record};
+    use crate::day;
+
+    fn get_mock_result() -> PartResult {
+        PartResult {
+            day: day!(3),
+            part: 2,
+            answer: Some("42".into()),
+            duration: Duration::from_nanos(74_130),
+            samples: 100,
+        }
+    }
+
+    #[test]
+    fn parses_output_formats() {
+        assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert_eq!(
+            "md".parse::<OutputFormat>().unwrap(),
+            OutputFormat::Markdown
+        );
+        assert!("html".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn round_trips_records() {
+        let records = [
+            Record::Answer(get_mock_result()),
+            Record::Part(PartResult {
+                answer: None,
+                ..get_mock_result()
+            }),
+            Record::Bench {
+                day: day!(1),
+                part: 1,
+                iterations: 10,
+            },
+            Record::Progress {
+                day: day!(1),
+                part: 2,
+                done: 5,
+                iterations: 10,
+            },
+            Record::Failed {
+                day: day!(2),
+                error: "the solution failed to build or run (exit status: 101).".into(),
+            },
+            Record::Total { total_millis: 12.5 },
+        ];
+
+        for record in records {
+            assert_eq!(record.to_json().parse::<Record>(), Ok(record));
+        }
+    }
+
+    #[test]
+    fn rejects_unrelated_lines() {
+        assert!("Part 1: 42".parse::<Record>().is_err());
+        assert!(r#"{ "type": "unknown" }"#.parse::<Record>().is_err());
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(get_mock_result().duration_str(), "74.1µs");
+    }
+}
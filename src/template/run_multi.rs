@@ -1,58 +1,91 @@
-use std::{collections::HashSet, io};
+use std::{collections::HashSet, fmt::Display, io, process::ExitStatus};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::render::OutputFormat;
+use crate::template::{Day, Year, paths};
 
-use super::{
-    all_days,
-    timings::{Timing, Timings},
-};
+use super::timings::{Timing, Timings};
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    format: OutputFormat,
+) -> Option<Timings> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut renderer = format.renderer();
+    renderer.start(
+        &year
+            .days()
+            .filter(|day| days_to_run.contains(day))
+            .collect::<Vec<_>>(),
+    );
 
     let mut need_space = false;
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    // NOTE: use non-duplicate, sorted day values of this year's calendar.
+    year.days()
         .filter(|day| days_to_run.contains(day))
         .for_each(|day| {
-            if need_space {
+            if need_space && matches!(format, OutputFormat::Plain | OutputFormat::Ansi) {
                 println!();
             }
             need_space = true;
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+            renderer.day_started(day);
+
+            let results = match child_commands::run_solution(
+                year,
+                day,
+                is_timed,
+                is_release,
+                None,
+                renderer.as_mut(),
+            ) {
+                Ok(results) => results,
+                Err(e) => {
+                    renderer.day_failed(day, &e.to_string());
+                    return;
+                }
+            };
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            renderer.day_finished(day, !results.is_empty());
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+            if !results.is_empty() {
+                timings.push(child_commands::parse_exec_time(&results, day));
             }
         });
 
     if is_timed {
         let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
-        );
+        renderer.finish(Some(timings.total_millis()));
         Some(timings)
     } else {
+        renderer.finish(None);
         None
     }
 }
 
-#[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
     IO(io::Error),
+    /// The solution did not build, or it panicked.
+    Failed(ExitStatus),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BrokenPipe => write!(f, "could not capture the output of the solution."),
+            Self::IO(e) => write!(f, "could not run the solution: {e}"),
+            Self::Failed(status) => write!(f, "the solution failed to build or run ({status})."),
+        }
+    }
 }
 
+impl std::error::Error for Error {}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Self::IO(e)
@@ -60,75 +93,159 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    paths::bin_path(year, day)
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as parsing their results.
 pub mod child_commands {
     use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use crate::template::{
+        Day, Year, config, paths,
+        render::{PartResult, Record, Renderer},
+        vault,
+    };
     use std::{
-        io::{BufRead, BufReader},
+        io::{self, BufRead, BufReader, Read},
         path::Path,
         process::{Command, Stdio},
-        thread,
+        sync::mpsc::{self, Sender},
+        thread::{self, JoinHandle},
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+    /// Run the solution bin for a given day, forwarding its results to `renderer` as they arrive.
+    /// Returns the final result of every part that was run.
+    ///
+    /// The solution reads the file at `input` instead of the day's input, if passed.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`Error::Failed`] if the solution did not build or exited with an error,
+    /// e.g. because a part panicked, or [`Error::IO`] if its output could not be read.
+    pub fn run_solution(
+        year: Year,
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        input: Option<&str>,
+        renderer: &mut dyn Renderer,
+    ) -> Result<Vec<PartResult>, Error> {
+        // skip command invocation for days that have not been scaffolded or downloaded yet,
+        // which is not a failure of the solution.
+        if !Path::new(&get_path_for_bin(year, day)).exists()
+            || (input.is_none() && !vault::exists(paths::input_path(year, day)))
+        {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = paths::bin_name(year, day);
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
 
         if is_release {
             args.push("--release");
         }
 
+        // ask the child for structured records, which are rendered by the parent.
+        args.extend(["--", "--format", "json"]);
+
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
+        if let Some(input) = input {
+            args.extend(["--input", input]);
+        }
+
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr while grabbing result records.
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
+        let mut cmd = Command::new("cargo");
+        cmd.args(&args)
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
-
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
-
-        let mut output = vec![];
+            .stderr(Stdio::piped());
+        config::get().export(&mut cmd);
+        let mut cmd = cmd.spawn()?;
+
+        let stdout = cmd.stdout.take().ok_or(super::Error::BrokenPipe)?;
+        let stderr = cmd.stderr.take().ok_or(super::Error::BrokenPipe)?;
+
+        // both streams are read from threads, so that the renderer sees their lines in order
+        // and keeps control of the terminal.
+        let (sender, receiver) = mpsc::channel();
+        let readers = [
+            spawn_reader(Stream::Stdout, stdout, sender.clone()),
+            spawn_reader(Stream::Stderr, stderr, sender),
+        ];
+
+        let mut results = vec![];
+        let mut read_error = None;
+
+        for (stream, line) in receiver {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => {
+                    read_error.get_or_insert(e);
+                    continue;
+                }
+            };
+            // the output of a panic or a compiler error may not be UTF-8.
+            let line = String::from_utf8_lossy(&line);
+            let line = line.strip_suffix('\r').unwrap_or(&line);
+            if stream == Stream::Stderr {
+                renderer.diagnostic(line);
+                continue;
+            }
+            match line.parse::<Record>() {
+                Ok(record) => {
+                    record.render(renderer);
+                    if let Record::Part(result) = record {
+                        results.push(result);
+                    }
+                }
+                Err(_) => renderer.passthrough(line),
+            }
+        }
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+        let status = cmd.wait()?;
+        for reader in readers {
+            let _ = reader.join();
+        }
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        if let Some(e) = read_error {
+            return Err(e.into());
+        }
+        if !status.success() {
+            return Err(Error::Failed(status));
         }
+        Ok(results)
+    }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Stream {
+        Stdout,
+        Stderr,
+    }
 
-        Ok(output)
+    /// Sends every line of `stream` to `sender` until the stream closes or fails to read.
+    fn spawn_reader(
+        stream: Stream,
+        reader: impl Read + Send + 'static,
+        sender: Sender<(Stream, io::Result<Vec<u8>>)>,
+    ) -> JoinHandle<()> {
+        thread::spawn(move || {
+            for line in BufReader::new(reader).split(b'\n') {
+                let failed = line.is_err();
+                if sender.send((stream, line)).is_err() || failed {
+                    break;
+                }
+            }
+        })
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
+    /// Collects the timings of all solved parts. Parts without an answer are not counted.
+    pub fn parse_exec_time(results: &[PartResult], day: Day) -> super::Timing {
         let mut timings = super::Timing {
             day,
             part_1: None,
@@ -136,60 +253,26 @@ pub mod child_commands {
             total_nanos: 0_f64,
         };
 
-        output
+        results
             .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
+            .filter(|result| result.answer.is_some())
+            .for_each(|result| {
+                if result.part == 1 {
+                    timings.part_1 = Some(result.duration_str());
+                } else if result.part == 2 {
+                    timings.part_2 = Some(result.duration_str());
                 }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                #[allow(clippy::cast_precision_loss)]
+                {
+                    timings.total_nanos += result.duration.as_nanos() as f64;
                 }
-
-                timings.total_nanos += nanos;
             });
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
-
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
+    /// copied from: <https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333>
     #[cfg(feature = "test_lib")]
     macro_rules! assert_approx_eq {
         ($a:expr, $b:expr) => {{
@@ -205,50 +288,50 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
+        use std::time::Duration;
+
         use super::parse_exec_time;
 
-        use crate::day;
+        use crate::{day, template::render::PartResult};
+
+        fn result(part: u8, answer: Option<&str>, nanos: u64) -> PartResult {
+            PartResult {
+                day: day!(1),
+                part,
+                answer: answer.map(Into::into),
+                duration: Duration::from_nanos(nanos),
+                samples: 100,
+            }
+        }
 
         #[test]
         fn parses_execution_times() {
             let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
+                &[result(1, Some("0"), 74), result(2, Some("10"), 74_130_000)],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
+        fn parses_multiline_answers() {
             let res = parse_exec_time(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    result(1, Some("#..\n.#.\n..#"), 2_000_000_000),
+                    result(2, Some("10s"), 100_000_000),
                 ],
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part_1.unwrap(), "2.0s");
+            assert_eq!(res.part_2.unwrap(), "100.0ms");
         }
 
         #[test]
         fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
+            let res = parse_exec_time(&[result(1, None, 10), result(2, None, 10)], day!(1));
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
@@ -1,40 +1,128 @@
 /// Encapsulates code that interacts with solution functions.
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{Write, stdout};
-use std::process::Output;
+use std::marker::PhantomData;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, process};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::answer_cache::{self, AnswerCache, CachedAnswer};
+use crate::template::render::{PartResult, Renderer};
+use crate::template::submission::{self, SubmitOptions};
+use crate::template::{Day, Year, config};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// Runs a part and reports its answer. `numeric` is set for parts that return an integer,
+/// see [`Answer`].
+pub fn run_part<I: Copy + AsRef<[u8]>, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+    numeric: bool,
+    renderer: &mut dyn Renderer,
+) {
+    // the final day only has one part, skip the placeholder for the second one.
+    if part > year.parts(day) {
+        return;
+    }
 
-    let (result, duration, samples) = run_timed(func, input, |result| {
-        print_result(result.as_ref(), &part_str, "");
+    let (result, duration, samples) = run_timed(func, input, day, part, renderer);
+
+    renderer.part_finished(&PartResult {
+        day,
+        part,
+        answer: result.as_ref().map(ToString::to_string),
+        duration,
+        samples,
     });
 
-    print_result(
-        result.as_ref(),
-        &part_str,
-        &format_duration(&duration, samples),
-    );
+    // answers computed from another file than the day's input must not be cached or submitted.
+    if let Some(result) = result
+        && input_arg().is_none()
+    {
+        let answer = result.to_string();
+        cache_answer(year, day, part, &answer, numeric, input.as_ref());
+        if submit_arg() == Some(part) {
+            submission::submit(
+                year,
+                day,
+                part,
+                &answer,
+                numeric,
+                SubmitOptions::from_args(),
+            );
+        }
+    }
+}
+
+/// The answer type of a part, used by `solution!` to tell whether its answers are plain numbers.
+///
+/// `(&Answer::of(&part_one)).is_numeric()` picks [`IntegerAnswer`] for the integer types and
+/// falls back to [`OtherAnswer`] through auto-ref for everything else.
+pub struct Answer<T>(PhantomData<T>);
+
+impl<T> Answer<T> {
+    pub const fn of<I>(_func: &impl Fn(I) -> Option<T>) -> Self {
+        Self(PhantomData)
+    }
+}
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+/// The primitive integer types.
+pub trait Integer {}
+
+macro_rules! impl_integer {
+    ($($t:ty)*) => {
+        $(impl Integer for $t {})*
+    };
+}
+
+impl_integer!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+pub trait IntegerAnswer {
+    fn is_numeric(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Integer> IntegerAnswer for Answer<T> {}
+
+pub trait OtherAnswer {
+    fn is_numeric(&self) -> bool {
+        false
+    }
+}
+
+impl<T> OtherAnswer for &Answer<T> {}
+
+/// Remembers the latest answer of a part computed from `input`, so that `cargo submit` can
+/// send it later.
+fn cache_answer(year: Year, day: Day, part: u8, answer: &str, numeric: bool, input: &[u8]) {
+    let mut cache = AnswerCache::read_from_file(year);
+    cache.set(
+        day,
+        part,
+        CachedAnswer {
+            answer: answer.into(),
+            input_hash: answer_cache::hash_input(input),
+            numeric,
+            computed_at: answer_cache::now(),
+        },
+    );
+    if let Err(e) = cache.store_file(year) {
+        eprintln!("Failed to cache answer: {e}");
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Copy, T>(
-    func: impl Fn(I) -> T,
+///  2. in release, the function is benched (approx. `bench.target_ms` of execution time or `bench.min_samples` samples, whatever take longer.)
+fn run_timed<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    day: Day,
+    part: u8,
+    renderer: &mut dyn Renderer,
+) -> (Option<T>, Duration, u128) {
     let timer = Instant::now();
     let result = {
         #[cfg(feature = "dhat-heap")]
@@ -44,10 +132,25 @@ fn run_timed<I: Copy, T>(
     };
     let base_time = timer.elapsed();
 
-    hook(&result);
+    renderer.part_answered(&PartResult {
+        day,
+        part,
+        answer: result.as_ref().map(ToString::to_string),
+        duration: base_time,
+        samples: 1,
+    });
 
     let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+        let settings = config::get().bench;
+        let bench_iterations = (Duration::from_millis(settings.target_ms).as_nanos()
+            / cmp::max(base_time.as_nanos(), 10))
+        .clamp(settings.min_samples, settings.max_samples);
+
+        renderer.bench_started(day, part, bench_iterations);
+        let duration = bench(func, input, bench_iterations, |done| {
+            renderer.bench_progress(day, part, done, bench_iterations);
+        });
+        (duration, bench_iterations)
     } else {
         (base_time, 1)
     };
@@ -55,28 +158,29 @@ fn run_timed<I: Copy, T>(
     (result, run.0, run.1)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
-
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
-
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
-
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    bench_iterations: u128,
+    mut on_progress: impl FnMut(u128),
+) -> Duration {
     let mut timers: Vec<Duration> = vec![];
 
-    for _ in 0..bench_iterations {
+    // report progress in steps of 5%, the timer excludes the reporting itself.
+    let progress_step = cmp::max(bench_iterations / 20, 1);
+
+    for i in 1..=bench_iterations {
         let timer = Instant::now();
         black_box(func(black_box(input)));
         timers.push(timer.elapsed());
+
+        if i % progress_step == 0 {
+            on_progress(i);
+        }
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    #[allow(clippy::cast_possible_truncation)]
+    Duration::from_nanos(average_duration(&timers) as u64)
 }
 
 fn average_duration(numbers: &[Duration]) -> u128 {
@@ -87,86 +191,45 @@ fn average_duration(numbers: &[Duration]) -> u128 {
         / numbers.len() as u128
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+/// Reads the path passed with `--input`, which replaces the input of the day, e.g. in `cargo crosscheck`.
+#[must_use]
+pub fn input_arg() -> Option<String> {
+    match pico_args::Arguments::from_env().opt_value_from_str("--input") {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!(
+                "Unexpected command-line input: {e}. Format: --input data/2025/examples/01.txt"
+            );
+            process::exit(1);
+        }
     }
 }
 
-fn print_result<T: Display>(result: Option<&T>, part: &str, duration_str: &str) {
-    let is_intermediate_result = duration_str.is_empty();
-
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
-                }
-            } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                }
-            }
-        }
-        None => {
-            if is_intermediate_result {
-                print!("{part}: ✖");
-            } else {
-                print!("\r");
-                println!("{part}: ✖             ");
-            }
+/// Reads the part passed with `--submit`, if any.
+fn submit_arg() -> Option<u8> {
+    match pico_args::Arguments::from_env().opt_value_from_str("--submit") {
+        Ok(part) => part,
+        Err(e) => {
+            eprintln!("Unexpected command-line input: {e}. Format: cargo solve 1 --submit 1");
+            process::exit(1);
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
-
-    if !args.contains(&"--submit".into()) {
-        return None;
-    }
-
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    }
-
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+/* -------------------------------------------------------------------------- */
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    };
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answer, IntegerAnswer, OtherAnswer};
 
-    if part_submit != part {
-        return None;
-    }
+    #[test]
+    fn detects_integer_answers() {
+        let integer = |_: &str| Some(-12_i64);
+        let text = |_: &str| Some(String::from("EFUGLPAP"));
+        let bytes = |_: &[u8]| Some(42_usize);
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
+        assert!((&Answer::of(&integer)).is_numeric());
+        assert!(!(&Answer::of(&text)).is_numeric());
+        assert!((&Answer::of(&bytes)).is_numeric());
     }
-
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
 }
@@ -0,0 +1,168 @@
+//! Filters that narrow down a [`DaySet`] for commands that run multiple days.
+
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    path::Path,
+    process::{Command, Output},
+};
+
+use crate::template::{Day, DaySet, Year, paths, timings::Timings};
+
+/// Optional filters applied on top of a day selection expression.
+#[derive(Debug, Clone, Default)]
+pub struct DayFilters {
+    /// Only keep days that do not have a complete stored timing.
+    pub unsolved: bool,
+    /// Only keep the `n` days with the highest stored total time.
+    pub slowest: Option<usize>,
+    /// Only keep days whose bin or data files changed since the given git ref.
+    pub changed_since: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum DayFilterError {
+    GitNotCallable,
+    BadExitStatus(Output),
+}
+
+impl Display for DayFilterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::GitNotCallable => write!(f, "git could not be called."),
+            Self::BadExitStatus(output) => write!(
+                f,
+                "git exited with a non-zero status: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        }
+    }
+}
+
+impl DayFilters {
+    /// Applies all enabled filters to `days`, returning the days that pass every one of them.
+    pub(crate) fn apply(
+        &self,
+        year: Year,
+        days: DaySet,
+        timings: &Timings,
+    ) -> Result<DaySet, DayFilterError> {
+        let mut days = days.into_inner();
+
+        if self.unsolved {
+            days.retain(|day| !timings.is_day_complete(year, *day));
+        }
+
+        if let Some(n) = self.slowest {
+            let slowest = slowest_days(timings, n);
+            days.retain(|day| slowest.contains(day));
+        }
+
+        if let Some(git_ref) = &self.changed_since {
+            let changed = changed_days(year, git_ref)?;
+            days.retain(|day| changed.contains(day));
+        }
+
+        Ok(days.into())
+    }
+}
+
+fn slowest_days(timings: &Timings, n: usize) -> HashSet<Day> {
+    let mut data: Vec<_> = timings.data.iter().collect();
+    data.sort_unstable_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos));
+    data.iter().take(n).map(|t| t.day).collect()
+}
+
+fn changed_days(year: Year, git_ref: &str) -> Result<HashSet<Day>, DayFilterError> {
+    // include untracked files so that freshly scaffolded days count as changed.
+    let diff = call_git(&["diff", "--name-only", git_ref, "--"])?;
+    let untracked = call_git(&["ls-files", "--others", "--exclude-standard"])?;
+
+    Ok(diff
+        .lines()
+        .chain(untracked.lines())
+        .filter_map(|path| day_for_path(year, path))
+        .collect())
+}
+
+fn call_git(args: &[&str]) -> Result<String, DayFilterError> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|_| DayFilterError::GitNotCallable)?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(DayFilterError::BadExitStatus(output))
+    }
+}
+
+/// Maps a repository path to the day of `year` it belongs to,
+/// e.g. `src/bin/2025-05.rs` or `data/2025/examples/05-2.txt`.
+fn day_for_path(year: Year, path: &str) -> Option<Day> {
+    let path = Path::new(path);
+    let parent = path.parent()?.to_str()?;
+    let stem = path.file_stem()?.to_str()?;
+
+    let data_dir = paths::data_dir(year);
+    let day = if parent == "src/bin" {
+        stem.strip_prefix(&format!("{year}-"))?
+    } else if parent == format!("{data_dir}/inputs") || parent == format!("{data_dir}/examples") {
+        stem.split('-').next()?
+    } else {
+        return None;
+    };
+
+    day.parse().ok()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{day_for_path, slowest_days};
+    use crate::{
+        day,
+        template::timings::{Timing, Timings},
+        year,
+    };
+
+    #[test]
+    fn maps_paths_to_days() {
+        let year = year!(2025);
+        assert_eq!(day_for_path(year, "src/bin/2025-05.rs"), Some(day!(5)));
+        assert_eq!(
+            day_for_path(year, "data/2025/inputs/11.txt"),
+            Some(day!(11))
+        );
+        assert_eq!(
+            day_for_path(year, "data/2025/examples/03-2.txt"),
+            Some(day!(3))
+        );
+        assert_eq!(day_for_path(year, "src/bin/2024-05.rs"), None);
+        assert_eq!(day_for_path(year, "data/2024/inputs/11.txt"), None);
+        assert_eq!(day_for_path(year, "src/lib.rs"), None);
+        assert_eq!(day_for_path(year, "data/2025/puzzles/05.md"), None);
+    }
+
+    #[test]
+    fn selects_slowest_days() {
+        let timing = |day, total_nanos| Timing {
+            day,
+            part_1: None,
+            part_2: None,
+            total_nanos,
+        };
+        let timings = Timings {
+            data: vec![
+                timing(day!(1), 10.0),
+                timing(day!(2), 30.0),
+                timing(day!(3), 20.0),
+            ],
+        };
+
+        let slowest = slowest_days(&timings, 2);
+        assert_eq!(slowest.len(), 2);
+        assert!(slowest.contains(&day!(2)));
+        assert!(slowest.contains(&day!(3)));
+    }
+}
@@ -0,0 +1,363 @@
+//! Submits answers via aoc-cli and follows up on accepted ones.
+use std::{env, process, thread, time::Duration};
+
+use crate::template::{
+    Day, Year, answer_cache,
+    answers::Answers,
+    aoc_cli::{self, Verdict},
+    commands::extract_example,
+    config, markdown, paths, puzzle,
+    render::OutputFormat,
+    submission_log::SubmissionLog,
+    vault,
+};
+
+/// An answer that is about to be submitted, along with what is known about the part.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Candidate {
+    pub answer: String,
+    /// Whether the solution returns a numeric type.
+    pub numeric: bool,
+    /// The expected answer of the example, see `cargo extract-example`.
+    pub example_answer: Option<String>,
+    /// The answer that was already accepted for this part, see `cargo harvest`.
+    pub accepted_answer: Option<String>,
+}
+
+impl Candidate {
+    /// Collects the example answer and the accepted answer of `part` from the data folder.
+    #[must_use]
+    pub fn load(year: Year, day: Day, part: u8, answer: &str, numeric: bool) -> Self {
+        let example_answer = Answers::read_from_file(&paths::example_answers_path(year))
+            .get(day, part)
+            .map(ToString::to_string);
+
+        let accepted_answer = Answers::read_from_file(&paths::answers_path(year))
+            .get(day, part)
+            .map(ToString::to_string)
+            .or_else(|| {
+                let markdown = vault::read_to_string(paths::puzzle_path(year, day)).ok()?;
+                puzzle::submitted_answers(&markdown)
+                    .into_iter()
+                    .nth(usize::from(part) - 1)
+            });
+
+        Self {
+            answer: answer.into(),
+            numeric,
+            example_answer,
+            accepted_answer,
+        }
+    }
+}
+
+/// How a failed check affects the submission.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    /// The answer is submitted anyway.
+    Warn,
+    /// The answer is only submitted with `--force`.
+    Block,
+}
+
+/// The reason why a check considers an answer implausible.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Objection {
+    pub severity: Severity,
+    pub reason: String,
+}
+
+impl Objection {
+    fn warn(reason: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warn,
+            reason: reason.into(),
+        }
+    }
+
+    fn block(reason: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Block,
+            reason: reason.into(),
+        }
+    }
+}
+
+/// A plausibility check that runs before an answer is submitted.
+pub type Check = fn(&Candidate) -> Option<Objection>;
+
+/// The checks that run before every submission, in order.
+pub const CHECKS: &[Check] = &[
+    zero_or_empty,
+    example_answer,
+    multi_line,
+    non_numeric,
+    already_correct,
+];
+
+/// Runs all [`CHECKS`] against `candidate`.
+#[must_use]
+pub fn check(candidate: &Candidate) -> Vec<Objection> {
+    CHECKS.iter().filter_map(|check| check(candidate)).collect()
+}
+
+fn zero_or_empty(candidate: &Candidate) -> Option<Objection> {
+    match candidate.answer.trim() {
+        "" => Some(Objection::block("the answer is empty.")),
+        "0" => Some(Objection::block("the answer is zero.")),
+        _ => None,
+    }
+}
+
+fn example_answer(candidate: &Candidate) -> Option<Objection> {
+    let example = candidate.example_answer.as_deref()?;
+    (candidate.answer == example).then(|| {
+        Objection::block(format!(
+            "the answer equals the example answer `{example}`, was the solution run against the example?"
+        ))
+    })
+}
+
+fn multi_line(candidate: &Candidate) -> Option<Objection> {
+    candidate
+        .answer
+        .trim()
+        .contains('\n')
+        .then(|| Objection::block("the answer spans multiple lines."))
+}
+
+fn non_numeric(candidate: &Candidate) -> Option<Objection> {
+    let numeric = candidate.answer.bytes().all(|b| b.is_ascii_digit());
+    (candidate.numeric && !numeric).then(|| {
+        Objection::warn(format!(
+            "the solution returns a number, but `{}` is not a positive integer.",
+            candidate.answer
+        ))
+    })
+}
+
+/// Only resubmitting the accepted answer is blocked. A different answer is merely suspicious,
+/// the recorded answer may be wrong, e.g. harvested from a page that was parsed incorrectly.
+fn already_correct(candidate: &Candidate) -> Option<Objection> {
+    let accepted = candidate.accepted_answer.as_deref()?;
+    Some(if candidate.answer == accepted {
+        Objection::block("the answer was already accepted.")
+    } else {
+        Objection::warn(format!(
+            "the part was already solved with `{accepted}`, did the solution regress?"
+        ))
+    })
+}
+
+/// How an answer is submitted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SubmitOptions {
+    /// Submit even if a plausibility check blocks the answer.
+    pub force: bool,
+    /// Sleep through cooldowns and resubmit instead of giving up.
+    pub wait: bool,
+}
+
+impl SubmitOptions {
+    /// Reads `--force` and `--wait` from the arguments of the current process.
+    #[must_use]
+    pub fn from_args() -> Self {
+        Self {
+            force: env::args().any(|x| x == "--force"),
+            wait: env::args().any(|x| x == "--wait"),
+        }
+    }
+}
+
+/// Submits `answer` for `part` of `day` if it passes the plausibility [`CHECKS`], or if
+/// `options.force` is set. If part one is accepted, the puzzle description is refreshed to include part two.
+///
+/// The checks are skipped entirely if `submit.checks` is disabled.
+/// Every verdict is recorded in the submission log, accepted answers are also added to the answers store.
+/// Returns the final verdict, or `None` if the answer was not submitted.
+///
+/// Exits the process if aoc-cli is not installed.
+#[allow(clippy::must_use_candidate)]
+pub fn submit(
+    year: Year,
+    day: Day,
+    part: u8,
+    answer: &str,
+    numeric: bool,
+    options: SubmitOptions,
+) -> Option<Verdict> {
+    let defaults = config::get().submit;
+    let objections = if defaults.checks {
+        check(&Candidate::load(year, day, part, answer, numeric))
+    } else {
+        vec![]
+    };
+    for objection in &objections {
+        match objection.severity {
+            Severity::Warn => eprintln!("Warning: {}", objection.reason),
+            Severity::Block => eprintln!("Refusing to submit: {}", objection.reason),
+        }
+    }
+    if !options.force && objections.iter().any(|o| o.severity == Severity::Block) {
+        eprintln!("Pass --force to submit anyway.");
+        return None;
+    }
+
+    if aoc_cli::check().is_err() {
+        eprintln!(
+            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+        );
+        process::exit(1);
+    }
+
+    let verdict = loop {
+        println!("Submitting result via aoc-cli...");
+        let verdict = match aoc_cli::submit(year, day, part, answer) {
+            Ok(verdict) => verdict,
+            Err(e) => {
+                eprintln!("failed to call aoc-cli: {e}");
+                return None;
+            }
+        };
+        println!("Day {day} part {part}: {verdict}");
+        record(year, day, part, answer, &verdict);
+
+        match verdict {
+            Verdict::RateLimited(cooldown) if options.wait || defaults.wait => {
+                // a little slack, the server rounds the time left down.
+                let cooldown = cooldown + Duration::from_secs(1);
+                println!("Waiting {}s before resubmitting...", cooldown.as_secs());
+                thread::sleep(cooldown);
+            }
+            Verdict::RateLimited(_) => {
+                println!("Pass --wait to resubmit automatically once the cooldown ends.");
+                break verdict;
+            }
+            _ => break verdict,
+        }
+    };
+
+    if verdict == Verdict::Correct && part < year.parts(day) {
+        refresh_puzzle(year, day);
+    }
+    Some(verdict)
+}
+
+/// Appends `verdict` to the submission log and stores accepted answers.
+fn record(year: Year, day: Day, part: u8, answer: &str, verdict: &Verdict) {
+    let mut log = SubmissionLog::read_from_file(year);
+    log.push(day, part, answer, verdict, answer_cache::now());
+    if let Err(e) = log.store_file(year) {
+        eprintln!("Failed to record submission: {e}");
+    }
+
+    if *verdict == Verdict::Correct {
+        let answers_path = paths::answers_path(year);
+        let mut answers = Answers::read_from_file(&answers_path);
+        answers.set(day, part, answer.into());
+        if let Err(e) = answers.store_file(&answers_path) {
+            eprintln!("Failed to store answer: {e}");
+        }
+    }
+}
+
+/// Re-downloads the puzzle description after part one was accepted, so that the cached
+/// description includes part two. The input is left untouched.
+fn refresh_puzzle(year: Year, day: Day) {
+    println!("Part one accepted, fetching the description of part two...");
+    if let Err(e) = aoc_cli::download_puzzle(year, day) {
+        eprintln!("Failed to refresh puzzle: {e}");
+        return;
+    }
+
+    if let Some(part_two) = vault::read_to_string(paths::puzzle_path(year, day))
+        .ok()
+        .and_then(|markdown| puzzle::part_two(&markdown))
+    {
+        let ansi = OutputFormat::detect() == OutputFormat::Ansi;
+        let width = markdown::terminal_size().0.min(markdown::MAX_WIDTH);
+        print!("\n{}", markdown::render(&part_two, width, ansi));
+    }
+
+    // part two might come with an example of its own.
+    if let Err(e) = extract_example::extract(year, day, false) {
+        eprintln!("Skipping example extraction: {e}");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Candidate, Severity, check};
+
+    fn candidate(answer: &str) -> Candidate {
+        Candidate {
+            answer: answer.into(),
+            numeric: true,
+            example_answer: Some("142".into()),
+            accepted_answer: None,
+        }
+    }
+
+    fn severities(candidate: &Candidate) -> Vec<Severity> {
+        check(candidate).iter().map(|o| o.severity).collect()
+    }
+
+    #[test]
+    fn accepts_plausible_answers() {
+        assert!(check(&candidate("54331")).is_empty());
+    }
+
+    #[test]
+    fn blocks_zero_empty_and_multi_line_answers() {
+        assert_eq!(severities(&candidate("0")), [Severity::Block]);
+        assert_eq!(
+            severities(&Candidate {
+                numeric: false,
+                ..candidate("")
+            }),
+            [Severity::Block]
+        );
+        assert_eq!(
+            severities(&Candidate {
+                numeric: false,
+                ..candidate("#..#\n.##.")
+            }),
+            [Severity::Block]
+        );
+    }
+
+    #[test]
+    fn blocks_example_answers() {
+        assert_eq!(severities(&candidate("142")), [Severity::Block]);
+    }
+
+    #[test]
+    fn warns_about_non_numeric_answers_of_numeric_solutions() {
+        assert_eq!(severities(&candidate("-12")), [Severity::Warn]);
+        assert!(
+            check(&Candidate {
+                numeric: false,
+                ..candidate("EFUGLPAP")
+            })
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn blocks_solved_parts() {
+        let solved = Candidate {
+            accepted_answer: Some("54331".into()),
+            ..candidate("54331")
+        };
+        assert_eq!(severities(&solved), [Severity::Block]);
+        assert_eq!(
+            severities(&Candidate {
+                answer: "54330".into(),
+                ..solved
+            }),
+            [Severity::Warn]
+        );
+    }
+}
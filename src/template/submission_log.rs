@@ -0,0 +1,169 @@
+//! A log of every submitted answer and the verdict it received.
+use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year, aoc_cli::Verdict, paths};
+
+/// A submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    /// The name of the [`Verdict`], e.g. `too_high`.
+    pub verdict: String,
+    /// Seconds since the unix epoch.
+    pub submitted_at: u64,
+}
+
+/// The submissions of a year, oldest first.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SubmissionLog {
+    data: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn push(&mut self, day: Day, part: u8, answer: &str, verdict: &Verdict, submitted_at: u64) {
+        self.data.push(Submission {
+            day,
+            part,
+            answer: answer.into(),
+            verdict: verdict.name().into(),
+            submitted_at,
+        });
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Submission> {
+        self.data.iter()
+    }
+
+    /// Dehydrate the log to the JSON file of `year`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the file could not be written.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(paths::submissions_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the log from the JSON file of `year`. If not present, returns an empty log.
+    #[must_use]
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(paths::submissions_path(year))
+            .map_err(|x| x.to_string())
+            .and_then(Self::try_from)
+            .unwrap_or_default()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&SubmissionLog> for JsonValue {
+    fn from(value: &SubmissionLog) -> Self {
+        let data = value
+            .data
+            .iter()
+            .map(|submission| {
+                let map: HashMap<String, Self> = [
+                    ("day".to_string(), Self::String(submission.day.to_string())),
+                    ("part".to_string(), Self::Number(f64::from(submission.part))),
+                    (
+                        "answer".to_string(),
+                        Self::String(submission.answer.clone()),
+                    ),
+                    (
+                        "verdict".to_string(),
+                        Self::String(submission.verdict.clone()),
+                    ),
+                    (
+                        "submitted_at".to_string(),
+                        Self::String(submission.submitted_at.to_string()),
+                    ),
+                ]
+                .into();
+                Self::Object(map)
+            })
+            .collect();
+
+        Self::Object([("data".to_string(), Self::Array(data))].into())
+    }
+}
+
+impl TryFrom<String> for SubmissionLog {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let entries = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .and_then(|data| data.get::<Vec<JsonValue>>())
+            .ok_or("expected `json.data` to be an array.")?;
+
+        let mut data = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let entry = entry
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("expected a submission to be an object.")?;
+            let string = |key: &str| {
+                entry
+                    .get(key)
+                    .and_then(|v| v.get::<String>())
+                    .cloned()
+                    .ok_or_else(|| format!("expected `{key}` to be a string."))
+            };
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let part = entry
+                .get("part")
+                .and_then(|v| v.get::<f64>())
+                .map(|part| *part as u8)
+                .ok_or("expected `part` to be a number.")?;
+
+            data.push(Submission {
+                day: Day::from_str(&string("day")?).map_err(|e| e.to_string())?,
+                part,
+                answer: string("answer")?,
+                verdict: string("verdict")?,
+                submitted_at: string("submitted_at")?
+                    .parse()
+                    .map_err(|_| "expected `submitted_at` to be a timestamp.")?,
+            });
+        }
+
+        Ok(Self { data })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::SubmissionLog;
+    use crate::{day, template::aoc_cli::Verdict};
+
+    #[test]
+    fn round_trips_json() {
+        let mut log = SubmissionLog::default();
+        log.push(day!(4), 1, "1200", &Verdict::TooHigh, 1_733_300_000);
+        log.push(day!(4), 1, "1100", &Verdict::Correct, 1_733_300_100);
+
+        let json = JsonValue::from(&log).stringify().unwrap();
+        let parsed = SubmissionLog::try_from(json).unwrap();
+        assert_eq!(parsed, log);
+        assert_eq!(
+            parsed
+                .iter()
+                .map(|s| s.verdict.as_str())
+                .collect::<Vec<_>>(),
+            ["too_high", "correct"]
+        );
+    }
+}
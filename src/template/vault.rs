@@ -0,0 +1,374 @@
+//! Encrypted copies of inputs and puzzle descriptions, which may be committed to a public repository.
+//!
+//! A locked file lives next to its plaintext with an `.enc` suffix, e.g. `data/2025/inputs/01.txt.enc`.
+//! Reading a file through this module falls back to its locked copy when the plaintext is missing.
+//! The key is a random 256-bit key, created with `cargo inputs keygen` and read from `AOC_INPUTS_KEY`
+//! or the file configured with `inputs.key_file`. It is not derived from a passphrase, which could be
+//! brute-forced offline against the public `.enc` files.
+use std::{
+    env,
+    ffi::OsString,
+    fmt::{Display, Write},
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use chacha20poly1305::{
+    ChaCha20Poly1305, Nonce,
+    aead::{Aead, KeyInit},
+};
+use sha2::{Digest, Sha256};
+
+use crate::template::{Day, Year, config, paths};
+
+/// The environment variable holding the key, it takes precedence over the key file.
+pub const KEY_VAR: &str = "AOC_INPUTS_KEY";
+
+/// Appended to the path of a plaintext file to get the path of its locked copy.
+const SUFFIX: &str = "enc";
+
+/// Identifies locked files and the version of their format.
+const MAGIC: &[u8] = b"aoc-vault-1\n";
+
+const NONCE_LEN: usize = 12;
+
+const KEY_LEN: usize = 32;
+
+#[derive(Debug)]
+pub enum VaultError {
+    NoKey,
+    /// The key is not 64 hexadecimal digits, e.g. it is a passphrase.
+    InvalidKey,
+    Io(PathBuf, io::Error),
+    /// The file is not a locked file, or it was truncated.
+    Malformed(PathBuf),
+    /// The file was locked with another key, or it was tampered with.
+    WrongKey(PathBuf),
+}
+
+impl Display for VaultError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoKey => write!(
+                f,
+                "no key for the encrypted inputs. Run `cargo inputs keygen` or set `{KEY_VAR}`."
+            ),
+            Self::InvalidKey => write!(
+                f,
+                "the key in `{KEY_VAR}` or \"{}\" is not valid, expecting 64 hexadecimal digits as written by `cargo inputs keygen`.",
+                config::get().inputs.key_file
+            ),
+            Self::Io(path, e) => write!(f, "could not access \"{}\": {e}", path.display()),
+            Self::Malformed(path) => write!(f, "\"{}\" is not an encrypted file.", path.display()),
+            Self::WrongKey(path) => write!(
+                f,
+                "could not decrypt \"{}\", it was locked with a different key.",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for VaultError {}
+
+/// A random 256-bit key.
+pub struct Key {
+    cipher: ChaCha20Poly1305,
+    /// Secret salt of the nonces, see [`Key::encrypt`].
+    nonce_key: [u8; 32],
+}
+
+impl Key {
+    fn new(key: &[u8; KEY_LEN]) -> Self {
+        let derive = |purpose: &str| {
+            Sha256::new()
+                .chain_update(purpose)
+                .chain_update(key)
+                .finalize()
+        };
+        Self {
+            cipher: ChaCha20Poly1305::new(&derive("advent-of-code inputs\0")),
+            nonce_key: derive("advent-of-code nonces\0").into(),
+        }
+    }
+
+    /// Generates a new key from the random number generator of the operating system,
+    /// returning it with its hexadecimal encoding.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the operating system could not provide random bytes.
+    pub fn generate() -> Result<(Self, String), getrandom::Error> {
+        let mut key = [0; KEY_LEN];
+        getrandom::getrandom(&mut key)?;
+        let hex = key.iter().fold(String::new(), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        });
+        Ok((Self::new(&key), hex))
+    }
+
+    /// Parses a key of 64 hexadecimal digits, surrounding whitespace is ignored.
+    #[must_use]
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.trim().as_bytes();
+        if hex.len() != 2 * KEY_LEN {
+            return None;
+        }
+        let mut key = [0; KEY_LEN];
+        for (byte, digits) in key.iter_mut().zip(hex.chunks(2)) {
+            *byte = u8::from_str_radix(std::str::from_utf8(digits).ok()?, 16).ok()?;
+        }
+        Some(Self::new(&key))
+    }
+
+    /// Reads the key from `AOC_INPUTS_KEY`, falling back to the configured key file.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`VaultError::NoKey`] if neither holds a key, or [`VaultError::InvalidKey`]
+    /// if it is malformed.
+    pub fn load() -> Result<Self, VaultError> {
+        let hex = env::var(KEY_VAR)
+            .ok()
+            .or_else(|| fs::read_to_string(&config::get().inputs.key_file).ok())
+            .filter(|hex| !hex.trim().is_empty())
+            .ok_or(VaultError::NoKey)?;
+        Self::from_hex(&hex).ok_or(VaultError::InvalidKey)
+    }
+
+    /// Encrypts `plaintext`. The nonce is derived from the key and the plaintext, so that
+    /// locking an unchanged file again yields the same bytes and does not show up in `git diff`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `plaintext` exceeds the message size of the cipher, which is 256 GiB.
+    #[must_use]
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let digest = Sha256::new()
+            .chain_update(self.nonce_key)
+            .chain_update(plaintext)
+            .finalize();
+        let nonce = Nonce::from_slice(&digest[..NONCE_LEN]);
+        let ciphertext = self
+            .cipher
+            .encrypt(nonce, plaintext)
+            .expect("plaintext fits into a single message");
+
+        [MAGIC, nonce.as_slice(), &ciphertext].concat()
+    }
+
+    /// Decrypts the contents of the locked file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`VaultError`] if `data` is malformed or was locked with a different key.
+    pub fn decrypt(&self, data: &[u8], path: &Path) -> Result<Vec<u8>, VaultError> {
+        let data = data
+            .strip_prefix(MAGIC)
+            .filter(|data| data.len() >= NONCE_LEN)
+            .ok_or_else(|| VaultError::Malformed(path.into()))?;
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        self.cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| VaultError::WrongKey(path.into()))
+    }
+}
+
+/// The path of the locked copy of `path`, e.g. `data/2025/inputs/01.txt.enc`.
+#[must_use]
+pub fn locked_path(path: impl AsRef<Path>) -> PathBuf {
+    let mut locked = OsString::from(path.as_ref());
+    locked.push(".");
+    locked.push(SUFFIX);
+    locked.into()
+}
+
+/// Returns `true` if `path` or its locked copy exists.
+#[must_use]
+pub fn exists(path: impl AsRef<Path>) -> bool {
+    path.as_ref().exists() || locked_path(path).exists()
+}
+
+/// Reads `path`, decrypting its locked copy if the plaintext is missing.
+///
+/// # Errors
+///
+/// Will return [`VaultError`] if neither file could be read, or the locked copy could not be decrypted.
+pub fn read(path: impl AsRef<Path>) -> Result<Vec<u8>, VaultError> {
+    let path = path.as_ref();
+    match fs::read(path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let locked = locked_path(path);
+            match fs::read(&locked) {
+                Ok(data) => Key::load()?.decrypt(&data, &locked),
+                Err(locked_e) if locked_e.kind() == io::ErrorKind::NotFound => {
+                    Err(VaultError::Io(path.into(), e))
+                }
+                Err(e) => Err(VaultError::Io(locked, e)),
+            }
+        }
+        result => result.map_err(|e| VaultError::Io(path.into(), e)),
+    }
+}
+
+/// Like [`read`], for text files.
+///
+/// # Errors
+///
+/// Will return [`VaultError`] if the file could not be read, see [`read`].
+pub fn read_to_string(path: impl AsRef<Path>) -> Result<String, VaultError> {
+    let path = path.as_ref();
+    let contents = read(path)?;
+    String::from_utf8(contents).map_err(|e| {
+        VaultError::Io(
+            path.into(),
+            io::Error::new(io::ErrorKind::InvalidData, e.utf8_error()),
+        )
+    })
+}
+
+/// The files of a day that are kept encrypted: the input and the puzzle description.
+#[must_use]
+pub fn day_files(year: Year, day: Day) -> [String; 2] {
+    [paths::input_path(year, day), paths::puzzle_path(year, day)]
+}
+
+/// Writes the locked copy of the plaintext file at `path`.
+///
+/// # Errors
+///
+/// Will return [`VaultError::Io`] if a file could not be read or written.
+pub fn lock(key: &Key, path: &Path) -> Result<PathBuf, VaultError> {
+    let plaintext = fs::read(path).map_err(|e| VaultError::Io(path.into(), e))?;
+    let locked = locked_path(path);
+    fs::write(&locked, key.encrypt(&plaintext)).map_err(|e| VaultError::Io(locked.clone(), e))?;
+    Ok(locked)
+}
+
+/// Restores the plaintext file at `path` from its locked copy.
+///
+/// # Errors
+///
+/// Will return [`VaultError`] if a file could not be read or written, or could not be decrypted.
+pub fn unlock(key: &Key, path: &Path) -> Result<(), VaultError> {
+    let locked = locked_path(path);
+    let data = fs::read(&locked).map_err(|e| VaultError::Io(locked.clone(), e))?;
+    fs::write(path, key.decrypt(&data, &locked)?).map_err(|e| VaultError::Io(path.into(), e))
+}
+
+/// Lists the plaintext inputs and puzzle descriptions that are staged for the next commit.
+///
+/// # Errors
+///
+/// Will return an error message if `git` could not be run, e.g. outside of a repository.
+pub fn staged_plaintext() -> Result<Vec<String>, String> {
+    let output = Command::new("git")
+        .args([
+            "diff",
+            "--cached",
+            "--name-only",
+            "--diff-filter=ACMR",
+            "-z",
+        ])
+        .output()
+        .map_err(|e| format!("could not run git: {e}"))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    let data_dir = config::get().data_dir.trim_start_matches("./").to_string();
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .split('\0')
+        .filter(|path| is_plaintext_data(path, &data_dir))
+        .map(String::from)
+        .collect())
+}
+
+/// Returns `true` for inputs and puzzle descriptions below `data_dir`, e.g. `data/2025/inputs/01.txt`.
+fn is_plaintext_data(path: &str, data_dir: &str) -> bool {
+    let Some(rest) = path
+        .strip_prefix(data_dir)
+        .and_then(|rest| rest.strip_prefix('/'))
+    else {
+        return false;
+    };
+    let parts: Vec<_> = rest.split('/').collect();
+    matches!(
+        parts.as_slice(),
+        [_, "inputs" | "puzzles", file]
+            if !file.starts_with('.') && !file.ends_with(&format!(".{SUFFIX}"))
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::Path;
+
+    use super::{Key, VaultError, is_plaintext_data, locked_path};
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn round_trips() {
+        let key = Key::from_hex(&format!("{KEY}\n")).unwrap();
+        let path = Path::new("01.txt.enc");
+        let locked = key.encrypt(b"1 2 3\n");
+
+        assert_ne!(&locked[locked.len() - 6..], b"1 2 3\n");
+        assert_eq!(key.decrypt(&locked, path).unwrap(), b"1 2 3\n");
+        assert_eq!(locked, key.encrypt(b"1 2 3\n"), "locking is deterministic");
+        assert_ne!(locked, key.encrypt(b"1 2 4\n"));
+    }
+
+    #[test]
+    fn rejects_wrong_key_and_garbage() {
+        let path = Path::new("01.txt.enc");
+        let locked = Key::from_hex(KEY).unwrap().encrypt(b"input");
+        let (other, _) = Key::generate().unwrap();
+
+        assert!(matches!(
+            other.decrypt(&locked, path),
+            Err(VaultError::WrongKey(_))
+        ));
+        assert!(matches!(
+            Key::from_hex(KEY).unwrap().decrypt(b"input", path),
+            Err(VaultError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn parses_keys() {
+        let (key, hex) = Key::generate().unwrap();
+        assert_eq!(hex.len(), 64);
+        let locked = key.encrypt(b"input");
+        assert_eq!(
+            Key::from_hex(&hex)
+                .unwrap()
+                .decrypt(&locked, Path::new(""))
+                .unwrap(),
+            b"input"
+        );
+
+        assert!(Key::from_hex("hunter2").is_none());
+        assert!(Key::from_hex(&KEY.replace('0', "g")).is_none());
+        assert!(Key::from_hex(&KEY[2..]).is_none());
+    }
+
+    #[test]
+    fn finds_plaintext_data() {
+        assert!(is_plaintext_data("data/2025/inputs/01.txt", "data"));
+        assert!(is_plaintext_data("data/2025/puzzles/01.md", "data"));
+        assert!(!is_plaintext_data("data/2025/inputs/01.txt.enc", "data"));
+        assert!(!is_plaintext_data("data/2025/inputs/.keep", "data"));
+        assert!(!is_plaintext_data("data/2025/examples/01.txt", "data"));
+        assert!(!is_plaintext_data("src/bin/2025-01.rs", "data"));
+        assert_eq!(
+            locked_path("data/2025/inputs/01.txt"),
+            Path::new("data/2025/inputs/01.txt.enc")
+        );
+    }
+}
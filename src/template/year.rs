@@ -0,0 +1,194 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "today")]
+use chrono::{Datelike, FixedOffset, Utc};
+
+use crate::template::{AllDays, Day};
+
+#[cfg(feature = "today")]
+use crate::template::SERVER_UTC_OFFSET;
+
+/// The first year Advent of Code took place.
+const FIRST_YEAR: u16 = 2015;
+
+/// The first year with a shortened, 12 day calendar.
+const FIRST_SHORT_YEAR: u16 = 2025;
+
+/// A valid year of Advent of Code (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2025).unwrap();
+/// assert_eq!(year.to_string(), "2025")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    #[must_use]
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR || year > 9999 {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    #[must_use]
+    pub const fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the number of the last day of advent in this year.
+    /// Years before 2025 had 25 days, later years have 12.
+    #[must_use]
+    pub const fn last_day(self) -> u8 {
+        if self.0 < FIRST_SHORT_YEAR { 25 } else { 12 }
+    }
+
+    /// Returns `true` if `day` is part of this year's calendar.
+    #[must_use]
+    pub const fn contains(self, day: Day) -> bool {
+        day.into_inner() <= self.last_day()
+    }
+
+    /// An iterator that yields every day of advent in this year.
+    #[must_use]
+    pub const fn days(self) -> AllDays {
+        AllDays::new(self)
+    }
+
+    /// Returns the number of parts of `day`. The final day of a calendar only has a
+    /// puzzle for part one, its second star is awarded for completing all other days.
+    #[must_use]
+    pub const fn parts(self, day: Day) -> u8 {
+        if day.into_inner() == self.last_day() {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Parses the year from a solution bin name like `2025-01`.
+    #[must_use]
+    pub const fn from_bin_name(name: &str) -> Option<Self> {
+        let bytes = name.as_bytes();
+        if bytes.len() < 5 || bytes[4] != b'-' {
+            return None;
+        }
+
+        let mut year = 0;
+        let mut i = 0;
+        while i < 4 {
+            if !bytes[i].is_ascii_digit() {
+                return None;
+            }
+            year = year * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        Self::new(year)
+    }
+}
+
+#[cfg(feature = "today")]
+impl Year {
+    /// Returns the current year on the Advent of Code server.
+    #[must_use]
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let today = Utc::now().with_timezone(&offset);
+        Self::new(u16::try_from(today.year()).ok()?)
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.trim().parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year of {FIRST_YEAR} or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const {
+            $crate::template::Year::new($year)
+                .expect("invalid year, expecting a value of 2015 or later")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+    use crate::day;
+
+    #[test]
+    fn derives_calendar_length() {
+        assert_eq!(Year(2024).last_day(), 25);
+        assert_eq!(Year(2025).last_day(), 12);
+        assert_eq!(Year(2024).days().count(), 25);
+        assert_eq!(Year(2025).days().count(), 12);
+        assert!(Year(2015).contains(day!(25)));
+        assert!(!Year(2025).contains(day!(13)));
+    }
+
+    #[test]
+    fn models_final_day_with_one_part() {
+        assert_eq!(Year(2025).parts(day!(11)), 2);
+        assert_eq!(Year(2025).parts(day!(12)), 1);
+        assert_eq!(Year(2024).parts(day!(12)), 2);
+        assert_eq!(Year(2024).parts(day!(25)), 1);
+    }
+
+    #[test]
+    fn parses_bin_names() {
+        assert_eq!(Year::from_bin_name("2025-01"), Some(Year(2025)));
+        assert_eq!(Year::from_bin_name("2015-25"), Some(Year(2015)));
+        assert_eq!(Year::from_bin_name("01"), None);
+        assert_eq!(Year::from_bin_name("2014-01"), None);
+        assert_eq!(Year::from_bin_name("20x5-01"), None);
+    }
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2020".parse::<Year>().unwrap(), Year(2020));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("twenty".parse::<Year>().is_err());
+    }
+}